@@ -0,0 +1,48 @@
+//! Compares emitting through a worker pool with spawning a thread per slot.
+//!
+//! Run with `cargo bench --bench pool`.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use sigs_slots::*;
+
+static BENCH: SignalId = signal!("bench");
+
+const SLOTS: usize = 8;
+const EMITS: usize = 2_000;
+
+fn setup(handler: SignalAndSlotHandler, counter: &Arc<AtomicUsize>) -> SignalAndSlotHandler {
+    for _ in 0..SLOTS {
+        let counter = Arc::clone(counter);
+        handler.connect(BENCH, create::int_slot(move |num: i32| -> Result<(), SlotError> {
+            counter.fetch_add(num as usize, Ordering::Relaxed);
+            Ok(())
+        })).unwrap();
+    }
+    handler
+}
+
+fn run(name: &str, handler: SignalAndSlotHandler) -> Duration {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let handler = setup(handler, &counter);
+
+    let start = Instant::now();
+    for _ in 0..EMITS {
+        handler.emit(BENCH, SlotArgs::Int(1)).unwrap();
+    }
+    let elapsed = start.elapsed();
+    handler.shutdown();
+
+    assert_eq!(counter.load(Ordering::Relaxed), SLOTS * EMITS);
+    println!("{:<16} {:>10.2?} total, {:>8.2?} per emit", name, elapsed, elapsed / EMITS as u32);
+    elapsed
+}
+
+fn main() {
+    println!("{} emits with {} slots each", EMITS, SLOTS);
+    let spawned = run("spawn and join", SignalAndSlotHandler::new());
+    let pooled = run("worker pool", SignalAndSlotHandler::with_pool(PoolConfig::default()));
+    println!("speedup: {:.1}x", spawned.as_secs_f64() / pooled.as_secs_f64());
+}
@@ -0,0 +1,37 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+use sigs_slots::*;
+
+static TICK: SignalId = signal!("tick");
+
+fn main() {
+    let handler = Arc::new(SignalAndSlotHandler::new());
+    let total = Arc::new(AtomicUsize::new(0));
+
+    let counter = Arc::clone(&total);
+    handler.connect_with(TICK, create::int_slot(move |num: i32| -> Result<(), SlotError> {
+        counter.fetch_add(num as usize, Ordering::Relaxed);
+        Ok(())
+    }), ConnectionType::Direct).unwrap();
+
+    //emitters don't block each other, and connecting doesn't block them either
+    let emitters = (0..4)
+        .map(|_| {
+            let handler = Arc::clone(&handler);
+            thread::spawn(move || {
+                for _ in 0..1000 {
+                    handler.emit(TICK, SlotArgs::Int(1)).unwrap();
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+    for _ in 0..100 {
+        handler.connect(String::from("other"), create::none_slot(|| -> Result<(), SlotError> { Ok(()) })).unwrap();
+    }
+    for emitter in emitters {
+        emitter.join().unwrap();
+    }
+
+    println!("ticks: {}", total.load(Ordering::Relaxed));
+}
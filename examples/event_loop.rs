@@ -0,0 +1,36 @@
+use std::sync::mpsc;
+use std::thread;
+use sigs_slots::*;
+
+fn draw(frame: i32) -> Result<(), SlotError> {
+    println!("drawing frame {} on {:?}", frame, thread::current().name());
+    Ok(())
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let handler = SignalAndSlotHandler::new();
+
+    //the render thread owns its event loop and hands out a handle to it
+    let (sender, receiver) = mpsc::channel();
+    let render = thread::Builder::new().name(String::from("render")).spawn(move || {
+        let event_loop = EventLoop::new();
+        sender.send(event_loop.handle()).unwrap();
+        event_loop.run();
+    })?;
+    let render_loop = receiver.recv()?;
+
+    handler.connect_on(String::from("frame"), create::int_slot(draw), &render_loop, ConnectionType::BlockingQueued)?;
+    for frame in 0..3 {
+        handler.emit(String::from("frame"), SlotArgs::Int(frame))?;
+    }
+
+    //a loop can also be pumped by hand, e.g. from an existing main loop
+    let local_loop = EventLoop::new();
+    handler.connect_on(String::from("frame"), create::int_slot(draw), &local_loop.handle(), ConnectionType::Queued)?;
+    handler.emit(String::from("frame"), SlotArgs::Int(3))?;
+    println!("processed {} events on main", local_loop.process_events());
+
+    render_loop.quit();
+    render.join().unwrap();
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use std::sync::{Arc, Mutex};
+use sigs_slots::combiner::{AllTrue, Collect, Sum};
+use sigs_slots::typed::Signal;
+
+#[derive(Clone)]
+struct Click {
+    x: i32,
+    y: i32,
+}
+
+fn main() {
+    let clicked: Signal<Click> = Signal::new();
+    let count = Arc::new(Mutex::new(0));
+
+    clicked.connect(|click| println!("clicked at {}, {}", click.x, click.y));
+    let counter = Arc::clone(&count);
+    let id = clicked.connect(move |_| *counter.lock().unwrap() += 1);
+
+    clicked.emit(Click { x: 1, y: 2 });
+    clicked.disconnect(id);
+    clicked.emit(Click { x: 3, y: 4 });
+
+    //only the first emit reached the counter
+    println!("counted {} clicks", count.lock().unwrap());
+
+    //clicked.emit(42); would not compile
+
+    //slots can return values, a combiner folds them into one result
+    let can_close: Signal<(), bool> = Signal::new();
+    can_close.connect(|_| true);
+    let unsaved = can_close.connect(|_| {
+        println!("editor: unsaved changes");
+        false
+    });
+    println!("close window: {}", can_close.emit_collect((), AllTrue));
+    can_close.disconnect(unsaved);
+    println!("close window: {}", can_close.emit_collect((), AllTrue));
+
+    let sizes: Signal<&'static str, usize> = Signal::new();
+    sizes.connect(|text| text.len());
+    sizes.connect(|text| text.split_whitespace().count());
+    println!("{:?} sum to {}", sizes.emit_collect("hello signal world", Collect), sizes.emit_collect("hello signal world", Sum));
+}
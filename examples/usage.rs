@@ -1,6 +1,9 @@
 use std::sync::{Arc, Mutex};
 use sigs_slots::*;
 
+//signals can be declared once instead of spelling out their name everywhere
+static PRINT_NUM: SignalId = signal!("print_num");
+
 #[derive(Debug)]
 struct Lol {
     data: i32,
@@ -36,38 +39,198 @@ fn print_num(num: i32) -> Result<(), String> {
 }
 
 
-fn main() {
-    let mut handler = SON_SIGNAL.lock().unwrap();
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let handler = &*SON_SIGNAL;
 
     //usage of functions
     let print_slot = create::none_slot(Box::new(print));
     let print_slot_num = create::int_slot(Box::new(print_num));
 
-    handler.connect(String::from("print"), print_slot);
+    handler.connect(String::from("print"), print_slot)?;
     //clone for use in multiple signals
-    handler.connect(String::from("print"), print_slot_num.clone());
-    handler.connect(String::from("print_num"), print_slot_num);
+    handler.connect(String::from("print"), print_slot_num.clone())?;
+    handler.connect(PRINT_NUM, print_slot_num)?;
 
     //note that only print_slot will be executed, because of the fitting argument
-    handler.emit(String::from("print"), SlotArgs::None);
+    handler.emit(String::from("print"), SlotArgs::None)?;
+
+    //in strict mode a slot with the wrong argument kind fails the whole emit
+    handler.set_mismatch_policy(MismatchPolicy::Strict);
+    if let Err(e) = handler.emit(String::from("print"), SlotArgs::Int(32)) {
+        println!("{}", e);
+    }
+    handler.set_mismatch_policy(MismatchPolicy::Ignore);
 
     //here print_num executes right the print_slot_num
-    handler.emit(String::from("print"), SlotArgs::Int(32));
-    handler.emit(String::from("print_num"), SlotArgs::Int(32));
+    handler.emit(String::from("print"), SlotArgs::Int(32))?;
+    handler.emit(PRINT_NUM, SlotArgs::Int(32))?;
+    //plain strings still work and name the same signal
+    handler.emit("print_num", SlotArgs::Int(33))?;
+
+    //declared signals reject slots and arguments of the wrong kind
+    handler.declare(PRINT_NUM, ArgKind::Int)?;
+    if let Err(e) = handler.connect(PRINT_NUM, create::none_slot(Box::new(print))) {
+        println!("{}", e);
+    }
+    if let Err(e) = handler.emit(PRINT_NUM, SlotArgs::None) {
+        println!("{}", e);
+    }
 
 
+    //failing slots show up in the report of the emit
+    handler.connect(String::from("fail"), create::int_slot(|num| Err(format!("can't handle {}", num))))?;
+    let report = handler.emit(String::from("fail"), SlotArgs::Int(7))?;
+    for failure in report.failures() {
+        if let SlotOutcome::Failed(e) = &failure.outcome {
+            println!("{:?} failed: {}", failure.connection, e);
+        }
+    }
+
     //usage of methods
 
     let lol1 = Arc::new(Mutex::new(Lol::new(42)));
     let print_method_slot = create::none_method_slot(Box::new(Lol::print), Arc::clone(&lol1));
     let print_method_add_slot = create::int_method_slot(Box::new(Lol::print_add), Arc::clone(&lol1));
 
-    handler.connect(String::from("print_method"), print_method_slot);
-    handler.connect(String::from("print_method"), print_method_add_slot.clone());
-    handler.connect(String::from("print_add_method"), print_method_add_slot);
+    handler.connect(String::from("print_method"), print_method_slot)?;
+    handler.connect(String::from("print_method"), print_method_add_slot.clone())?;
+    handler.connect(String::from("print_add_method"), print_method_add_slot)?;
+
+    handler.emit(String::from("print_method"), SlotArgs::None)?;
+    handler.emit(String::from("print_method"), SlotArgs::Int(42))?;
+    handler.emit(String::from("print_add_method"), SlotArgs::Int(42))?;
+
+    //removing connections again
+    let removed = handler.disconnect_receiver(&lol1);
+    println!("removed {} method slots", removed);
+    handler.emit(String::from("print_method"), SlotArgs::None)?;
+
+    let print_once = handler.connect(String::from("print_once"), create::none_slot(Box::new(print)))?;
+    handler.emit(String::from("print_once"), SlotArgs::None)?;
+    handler.disconnect(print_once);
+    handler.emit(String::from("print_once"), SlotArgs::None)?;
+    let print_once = handler.connect(String::from("print_once"), create::none_slot(Box::new(print)))?;
+
+    //single-shot slots disconnect themselves after running
+    handler.connect_once("ready", create::none_slot(Box::new(print)))?;
+    handler.connect_n("ready", create::none_slot(Box::new(print)), 2)?;
+    for _ in 0..3 {
+        let report = handler.emit("ready", SlotArgs::None)?;
+        println!("ready ran {} slots, still connected: {}", report.invoked(), handler.is_connected("ready"));
+    }
 
-    handler.emit(String::from("print_method"), SlotArgs::None);
-    handler.emit(String::from("print_method"), SlotArgs::Int(42));
-    handler.emit(String::from("print_add_method"), SlotArgs::Int(42));
+    //slots can emit through the singleton themselves
+    handler.connect(String::from("relay"), create::int_slot(|num: i32| {
+        SON_SIGNAL.emit(PRINT_NUM, SlotArgs::Int(num + 1)).map(|_| ()).map_err(SlotError::user)
+    }))?;
+    handler.emit(String::from("relay"), SlotArgs::Int(99))?;
+
+    //or forward one signal to another without a slot in between
+    handler.connect_forward("num_changed", PRINT_NUM)?;
+    handler.connect_forward_map("clicked", "num_changed", |_| SlotArgs::Int(1))?;
+    let report = handler.emit("clicked", SlotArgs::None)?;
+    println!("click reached {} slots", report.invoked());
+    if let Err(e) = handler.connect_forward(PRINT_NUM, "clicked") {
+        println!("{}", e);
+    }
 
+    //returning slots vote, a combiner folds their values
+    handler.connect("can_close", create::returning_slot(|()| -> Result<bool, SlotError> { Ok(true) }))?;
+    let lol4 = Arc::new(Mutex::new(Lol::new(0)));
+    handler.connect("can_close", create::returning_method_slot(|lol: &mut Lol, ()| -> Result<bool, SlotError> {
+        Ok(lol.data == 0)
+    }, Arc::clone(&lol4)))?;
+    println!("close window: {}", handler.emit_collect("can_close", SlotArgs::None, combiner::AllTrue)?);
+    lol4.lock().unwrap().data = 1;
+    println!("close window: {}", handler.emit_collect("can_close", SlotArgs::None, combiner::AllTrue)?);
+
+    //the wiring can be inspected at runtime
+    println!("{} connections on {:?}", handler.connection_count(), handler.signals());
+    for info in handler.receivers(PRINT_NUM) {
+        println!("{:?}: {:?} {:?} slot", info.id, info.slot_kind, info.arg_kind);
+    }
+    handler.set_label(print_once, "print");
+    //pipe this into `dot -Tsvg` to get a picture
+    println!("{}", handler.to_dot());
+
+    //scoped connections disconnect themselves when dropped
+    let local = SignalAndSlotHandler::new();
+    {
+        let lol2 = Arc::new(Mutex::new(Lol::new(7)));
+        let _guard = local.connect_scoped(String::from("widget"),
+            create::none_method_slot(Box::new(Lol::print), Arc::clone(&lol2)))?;
+        local.emit(String::from("widget"), SlotArgs::None)?;
+    }
+    //the guard is gone, so nothing is printed and lol2 is freed
+    local.emit(String::from("widget"), SlotArgs::None)?;
+
+    //weak method slots don't keep their receiver alive
+    let lol3 = Arc::new(Mutex::new(Lol::new(3)));
+    local.connect(String::from("weak"), create::weak_int_method_slot(Box::new(Lol::print_add), Arc::downgrade(&lol3)))?;
+    local.emit(String::from("weak"), SlotArgs::Int(1))?;
+    drop(lol3);
+    local.emit(String::from("weak"), SlotArgs::Int(1))?;
+    println!("pruned {} dead slots", local.pruned_count());
+
+    //connection types decide where a slot runs
+    local.connect_with(String::from("typed"), create::none_slot(Box::new(print)), ConnectionType::Direct)?;
+    local.connect_with(String::from("typed"), create::int_slot(Box::new(print_num)), ConnectionType::BlockingQueued)?;
+    local.emit(String::from("typed"), SlotArgs::None)?;
+    local.emit(String::from("typed"), SlotArgs::Int(8))?;
+
+    //emit_async returns right away, the handle collects the results
+    local.connect(String::from("slow"), create::none_slot(|| -> Result<(), SlotError> {
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        Ok(())
+    }))?;
+    let mut handle = local.emit_async(String::from("slow"), SlotArgs::None)?;
+    println!("finished right away: {}", handle.is_finished());
+    if handle.wait_timeout(std::time::Duration::from_secs(1)) {
+        println!("slow slot ok: {}", handle.report().is_some_and(|report| report.is_ok()));
+    }
+
+    //in sequential mode slots run one at a time, highest priority first
+    local.set_dispatch_mode(DispatchMode::Sequential);
+    local.connect("save", create::none_slot(|| -> Result<(), SlotError> {
+        println!("persisting");
+        Ok(())
+    }))?;
+    let validate = local.connect("save", create::none_slot(|| -> Result<(), SlotError> {
+        println!("validating");
+        Ok(())
+    }))?;
+    local.set_priority(validate, 10);
+    local.emit("save", SlotArgs::None)?;
+
+    //a slot can consume an emit so lower-priority slots don't see it
+    let dialog = local.connect("key", create::string_slot(|key: String| -> Result<(), SlotError> {
+        if key == "Escape" {
+            println!("dialog closed");
+            stop_propagation();
+        }
+        Ok(())
+    }))?;
+    local.set_priority(dialog, 1);
+    local.connect("key", create::string_slot(|key: String| -> Result<(), SlotError> {
+        println!("editor got {}", key);
+        Ok(())
+    }))?;
+    for key in ["a", "Escape"] {
+        let report = local.emit("key", SlotArgs::String(String::from(key)))?;
+        println!("stopped: {}", report.is_stopped());
+    }
+
+    //blocked signals run no slots, under BlockPolicy::Replay their emits wait for the unblock
+    local.set_block_policy(BlockPolicy::Replay);
+    {
+        let _blocker = SignalBlocker::new(&local, "save");
+        local.emit("save", SlotArgs::None)?;
+        println!("saving is blocked: {}", local.is_blocked("save"));
+    }
+
+    //single connections can be muted and keep their place in the order
+    local.set_enabled(validate, false);
+    local.emit("save", SlotArgs::None)?;
+    local.set_enabled(validate, true);
+    Ok(())
 }
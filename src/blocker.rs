@@ -0,0 +1,46 @@
+use super::{Signal, SignalAndSlotHandler};
+
+/// Guard that blocks a signal, or the whole handler, until it is dropped.
+///
+/// On drop the previous state is restored, so nested blockers of the same
+/// signal only unblock it once the outermost one is gone.
+pub struct SignalBlocker<'a> {
+    handler: &'a SignalAndSlotHandler,
+    //`None` blocks the whole handler
+    signal: Option<Signal>,
+    was_blocked: bool,
+}
+
+impl<'a> SignalBlocker<'a> {
+    pub fn new(handler: &'a SignalAndSlotHandler, signal: impl Into<Signal>) -> SignalBlocker<'a> {
+        let signal = signal.into();
+        SignalBlocker {
+            handler,
+            signal: Some(signal),
+            was_blocked: handler.block_signal(signal),
+        }
+    }
+
+    /// Blocks every signal of `handler`.
+    pub fn all(handler: &'a SignalAndSlotHandler) -> SignalBlocker<'a> {
+        SignalBlocker {
+            handler,
+            signal: None,
+            was_blocked: handler.set_blocked(true),
+        }
+    }
+}
+
+impl<'a> Drop for SignalBlocker<'a> {
+    fn drop(&mut self) {
+        match self.signal {
+            Some(signal) if !self.was_blocked => {
+                self.handler.unblock_signal(signal);
+            }
+            Some(_) => (),
+            None => {
+                self.handler.set_blocked(self.was_blocked);
+            }
+        }
+    }
+}
@@ -0,0 +1,82 @@
+/// Turns the return values of all slots of one emit into a single result.
+///
+/// Used by `typed::Signal::emit_collect` and `SignalAndSlotHandler::emit_collect`.
+/// A typed signal calls its slots lazily while the combiner advances
+/// `results`, so a combiner that stops early keeps the remaining slots from running.
+pub trait Combiner<R> {
+    type Output;
+
+    fn combine<I: Iterator<Item = R>>(self, results: I) -> Self::Output;
+}
+
+/// The value of the last slot, `None` if nothing is connected.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Last;
+
+impl<R> Combiner<R> for Last {
+    type Output = Option<R>;
+
+    fn combine<I: Iterator<Item = R>>(self, results: I) -> Option<R> {
+        results.last()
+    }
+}
+
+/// The value of the first slot. On a typed signal the other slots are not called.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct First;
+
+impl<R> Combiner<R> for First {
+    type Output = Option<R>;
+
+    fn combine<I: Iterator<Item = R>>(self, mut results: I) -> Option<R> {
+        results.next()
+    }
+}
+
+/// The values of all slots, in connection order.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Collect;
+
+impl<R> Combiner<R> for Collect {
+    type Output = Vec<R>;
+
+    fn combine<I: Iterator<Item = R>>(self, results: I) -> Vec<R> {
+        results.collect()
+    }
+}
+
+/// The sum of all values, zero if nothing is connected.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Sum;
+
+impl<R: std::iter::Sum> Combiner<R> for Sum {
+    type Output = R;
+
+    fn combine<I: Iterator<Item = R>>(self, results: I) -> R {
+        results.sum()
+    }
+}
+
+/// `true` unless a slot returns `false`. A typed signal stops calling slots at the first `false`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AllTrue;
+
+impl Combiner<bool> for AllTrue {
+    type Output = bool;
+
+    fn combine<I: Iterator<Item = bool>>(self, mut results: I) -> bool {
+        results.all(|result| result)
+    }
+}
+
+/// `true` if any slot returns `true`. A typed signal stops calling slots at the first `true`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AnyTrue;
+
+impl Combiner<bool> for AnyTrue {
+    type Output = bool;
+
+    fn combine<I: Iterator<Item = bool>>(self, mut results: I) -> bool {
+        results.any(|result| result)
+    }
+}
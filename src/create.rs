@@ -1,122 +1,200 @@
 use std::sync::Arc;
 use std::sync::Mutex;
+use std::sync::Weak;
 
-use super::Slot;
+use super::{Receiver, Slot, SlotArgs, SlotError, SlotValue};
 use std::any::Any;
 
-pub fn none_slot(func: Box<dyn Fn() -> Result<(), String> + Send + Sync + 'static>) -> Slot {
-    Slot::FnNone(Arc::new(Mutex::new(func)))
+pub fn none_slot<F, E>(func: F) -> Slot
+    where F: Fn() -> Result<(), E> + Send + Sync + 'static, E: Into<SlotError> {
+    Slot::FnNone(Arc::new(move || func().map_err(Into::into)))
 }
 
-pub fn int_slot(func: Box<dyn Fn(i32) -> Result<(), String> + Send + Sync + 'static>) -> Slot {
-    Slot::FnInt(Arc::new(Mutex::new(func)))
+pub fn int_slot<F, E>(func: F) -> Slot
+    where F: Fn(i32) -> Result<(), E> + Send + Sync + 'static, E: Into<SlotError> {
+    Slot::FnInt(function_arg(func))
 }
 
-pub fn int_array_slot(func: Box<dyn Fn(Vec<i32>) -> Result<(), String> + Send + Sync + 'static>) -> Slot {
-    Slot::FnIntArray(Arc::new(Mutex::new(func)))
+pub fn int_array_slot<F, E>(func: F) -> Slot
+    where F: Fn(Vec<i32>) -> Result<(), E> + Send + Sync + 'static, E: Into<SlotError> {
+    Slot::FnIntArray(function_arg(func))
 }
 
-pub fn float_slot(func: Box<dyn Fn(f32) -> Result<(), String> + Send + Sync + 'static>) -> Slot {
-    Slot::FnFloat(Arc::new(Mutex::new(func)))
+pub fn float_slot<F, E>(func: F) -> Slot
+    where F: Fn(f32) -> Result<(), E> + Send + Sync + 'static, E: Into<SlotError> {
+    Slot::FnFloat(function_arg(func))
 }
 
-pub fn float_array_slot(func: Box<dyn Fn(Vec<f32>) -> Result<(), String> + Send + Sync + 'static>) -> Slot {
-    Slot::FnFloatArray(Arc::new(Mutex::new(func)))
+pub fn float_array_slot<F, E>(func: F) -> Slot
+    where F: Fn(Vec<f32>) -> Result<(), E> + Send + Sync + 'static, E: Into<SlotError> {
+    Slot::FnFloatArray(function_arg(func))
 }
 
-pub fn bool_slot(func: Box<dyn Fn(bool) -> Result<(), String> + Send + Sync + 'static>) -> Slot {
-    Slot::FnBool(Arc::new(Mutex::new(func)))
+pub fn bool_slot<F, E>(func: F) -> Slot
+    where F: Fn(bool) -> Result<(), E> + Send + Sync + 'static, E: Into<SlotError> {
+    Slot::FnBool(function_arg(func))
 }
 
-pub fn bool_array_slot(func: Box<dyn Fn(Vec<bool>) -> Result<(), String> + Send + Sync + 'static>) -> Slot {
-    Slot::FnBoolArray(Arc::new(Mutex::new(func)))
+pub fn bool_array_slot<F, E>(func: F) -> Slot
+    where F: Fn(Vec<bool>) -> Result<(), E> + Send + Sync + 'static, E: Into<SlotError> {
+    Slot::FnBoolArray(function_arg(func))
 }
 
-pub fn string_slot(func: Box<dyn Fn(String) -> Result<(), String> + Send + Sync + 'static>) -> Slot {
-    Slot::FnString(Arc::new(Mutex::new(func)))
+pub fn string_slot<F, E>(func: F) -> Slot
+    where F: Fn(String) -> Result<(), E> + Send + Sync + 'static, E: Into<SlotError> {
+    Slot::FnString(function_arg(func))
 }
 
-pub fn string_array_slot(func: Box<dyn Fn(Vec<String>) -> Result<(), String> + Send + Sync + 'static>) -> Slot {
-    Slot::FnStringArray(Arc::new(Mutex::new(func)))
+pub fn string_array_slot<F, E>(func: F) -> Slot
+    where F: Fn(Vec<String>) -> Result<(), E> + Send + Sync + 'static, E: Into<SlotError> {
+    Slot::FnStringArray(function_arg(func))
 }
 
-pub fn none_method_slot<T: Any + Send + Sync>(func: Box<dyn Fn(&mut T) -> Result<(), String> + Send + Sync + 'static>, obj: Arc<Mutex<T>>) -> Slot {
-    Slot::FnNoneMethod((obj, Arc::new(Mutex::new(move |arg: &mut dyn Any| {
-        return match arg.downcast_mut::<T>() {
-            Some(value) => func(value),
-            None => Err(String::from("Couldn't cast for Method use")),
-        };
-    }))))
+pub fn none_method_slot<T, F, E>(func: F, obj: Arc<Mutex<T>>) -> Slot
+    where T: Any + Send + Sync, F: Fn(&mut T) -> Result<(), E> + Send + Sync + 'static, E: Into<SlotError> {
+    Slot::FnNoneMethod((Receiver::strong(obj), method_none(func)))
 }
 
-pub fn int_method_slot<T: Any + Send + Sync>(func: Box<dyn Fn(&mut T, i32) -> Result<(), String> + Send + Sync + 'static>, obj: Arc<Mutex<T>>) -> Slot {
-    Slot::FnIntMethod((obj, Arc::new(Mutex::new(move |arg: &mut dyn Any, data: i32| {
-        return match arg.downcast_mut::<T>() {
-            Some(value) => func(value, data),
-            None => Err(String::from("Couldn't cast for Method use")),
-        };
-    }))))
+pub fn int_method_slot<T, F, E>(func: F, obj: Arc<Mutex<T>>) -> Slot
+    where T: Any + Send + Sync, F: Fn(&mut T, i32) -> Result<(), E> + Send + Sync + 'static, E: Into<SlotError> {
+    Slot::FnIntMethod((Receiver::strong(obj), method_arg(func)))
 }
 
-pub fn int_array_method_slot<T: Any + Send + Sync>(func: Box<dyn Fn(&mut T, Vec<i32>) -> Result<(), String> + Send + Sync + 'static>, obj: Arc<Mutex<T>>) -> Slot {
-    Slot::FnIntArrayMethod((obj, Arc::new(Mutex::new(move |arg: &mut dyn Any, data: Vec<i32>| {
-        return match arg.downcast_mut::<T>() {
-            Some(value) => func(value, data),
-            None => Err(String::from("Couldn't cast for Method use")),
-        };
-    }))))
+pub fn int_array_method_slot<T, F, E>(func: F, obj: Arc<Mutex<T>>) -> Slot
+    where T: Any + Send + Sync, F: Fn(&mut T, Vec<i32>) -> Result<(), E> + Send + Sync + 'static, E: Into<SlotError> {
+    Slot::FnIntArrayMethod((Receiver::strong(obj), method_arg(func)))
 }
 
-pub fn float_method_slot<T: Any + Send + Sync>(func: Box<dyn Fn(&mut T, f32) -> Result<(), String> + Send + Sync + 'static>, obj: Arc<Mutex<T>>) -> Slot {
-    Slot::FnFloatMethod((obj, Arc::new(Mutex::new(move |arg: &mut dyn Any, data: f32| {
-        return match arg.downcast_mut::<T>() {
-            Some(value) => func(value, data),
-            None => Err(String::from("Couldn't cast for Method use")),
-        };
-    }))))
+pub fn float_method_slot<T, F, E>(func: F, obj: Arc<Mutex<T>>) -> Slot
+    where T: Any + Send + Sync, F: Fn(&mut T, f32) -> Result<(), E> + Send + Sync + 'static, E: Into<SlotError> {
+    Slot::FnFloatMethod((Receiver::strong(obj), method_arg(func)))
 }
 
-pub fn float_array_method_slot<T: Any + Send + Sync>(func: Box<dyn Fn(&mut T, Vec<f32>) -> Result<(), String> + Send + Sync + 'static>, obj: Arc<Mutex<T>>) -> Slot {
-    Slot::FnFloatArrayMethod((obj, Arc::new(Mutex::new(move |arg: &mut dyn Any, data: Vec<f32>| {
-        return match arg.downcast_mut::<T>() {
-            Some(value) => func(value, data),
-            None => Err(String::from("Couldn't cast for Method use")),
-        };
-    }))))
+pub fn float_array_method_slot<T, F, E>(func: F, obj: Arc<Mutex<T>>) -> Slot
+    where T: Any + Send + Sync, F: Fn(&mut T, Vec<f32>) -> Result<(), E> + Send + Sync + 'static, E: Into<SlotError> {
+    Slot::FnFloatArrayMethod((Receiver::strong(obj), method_arg(func)))
 }
 
-pub fn bool_method_slot<T: Any + Send + Sync>(func: Box<dyn Fn(&mut T, bool) -> Result<(), String> + Send + Sync + 'static>, obj: Arc<Mutex<T>>) -> Slot {
-    Slot::FnBoolMethod((obj, Arc::new(Mutex::new(move |arg: &mut dyn Any, data: bool| {
-        return match arg.downcast_mut::<T>() {
-            Some(value) => func(value, data),
-            None => Err(String::from("Couldn't cast for Method use")),
-        };
-    }))))
+pub fn bool_method_slot<T, F, E>(func: F, obj: Arc<Mutex<T>>) -> Slot
+    where T: Any + Send + Sync, F: Fn(&mut T, bool) -> Result<(), E> + Send + Sync + 'static, E: Into<SlotError> {
+    Slot::FnBoolMethod((Receiver::strong(obj), method_arg(func)))
 }
 
-pub fn bool_array_method_slot<T: Any + Send + Sync>(func: Box<dyn Fn(&mut T, Vec<bool>) -> Result<(), String> + Send + Sync + 'static>, obj: Arc<Mutex<T>>) -> Slot {
-    Slot::FnBoolArrayMethod((obj, Arc::new(Mutex::new(move |arg: &mut dyn Any, data: Vec<bool>| {
-        return match arg.downcast_mut::<T>() {
-            Some(value) => func(value, data),
-            None => Err(String::from("Couldn't cast for Method use")),
-        };
-    }))))
+pub fn bool_array_method_slot<T, F, E>(func: F, obj: Arc<Mutex<T>>) -> Slot
+    where T: Any + Send + Sync, F: Fn(&mut T, Vec<bool>) -> Result<(), E> + Send + Sync + 'static, E: Into<SlotError> {
+    Slot::FnBoolArrayMethod((Receiver::strong(obj), method_arg(func)))
 }
 
-pub fn string_method_slot<T: Any + Send + Sync>(func: Box<dyn Fn(&mut T, String) -> Result<(), String> + Send + Sync + 'static>, obj: Arc<Mutex<T>>) -> Slot {
-    Slot::FnStringMethod((obj, Arc::new(Mutex::new(move |arg: &mut dyn Any, data: String| {
-        return match arg.downcast_mut::<T>() {
-            Some(value) => func(value, data),
-            None => Err(String::from("Couldn't cast for Method use")),
-        };
-    }))))
+pub fn string_method_slot<T, F, E>(func: F, obj: Arc<Mutex<T>>) -> Slot
+    where T: Any + Send + Sync, F: Fn(&mut T, String) -> Result<(), E> + Send + Sync + 'static, E: Into<SlotError> {
+    Slot::FnStringMethod((Receiver::strong(obj), method_arg(func)))
 }
 
-pub fn string_array_method_slot<T: Any + Send + Sync>(func: Box<dyn Fn(&mut T, Vec<String>) -> Result<(), String> + Send + Sync + 'static>, obj: Arc<Mutex<T>>) -> Slot {
-    Slot::FnStringArrayMethod((obj, Arc::new(Mutex::new(move |arg: &mut dyn Any, data: Vec<String>| {
-        return match arg.downcast_mut::<T>() {
-            Some(value) => func(value, data),
-            None => Err(String::from("Couldn't cast for Method use")),
-        };
-    }))))
+pub fn string_array_method_slot<T, F, E>(func: F, obj: Arc<Mutex<T>>) -> Slot
+    where T: Any + Send + Sync, F: Fn(&mut T, Vec<String>) -> Result<(), E> + Send + Sync + 'static, E: Into<SlotError> {
+    Slot::FnStringArrayMethod((Receiver::strong(obj), method_arg(func)))
+}
+
+// weak variants, the receiver is not kept alive by the connection
+
+pub fn weak_none_method_slot<T, F, E>(func: F, obj: Weak<Mutex<T>>) -> Slot
+    where T: Any + Send + Sync, F: Fn(&mut T) -> Result<(), E> + Send + Sync + 'static, E: Into<SlotError> {
+    Slot::FnNoneMethod((Receiver::weak(obj), method_none(func)))
+}
+
+pub fn weak_int_method_slot<T, F, E>(func: F, obj: Weak<Mutex<T>>) -> Slot
+    where T: Any + Send + Sync, F: Fn(&mut T, i32) -> Result<(), E> + Send + Sync + 'static, E: Into<SlotError> {
+    Slot::FnIntMethod((Receiver::weak(obj), method_arg(func)))
+}
+
+pub fn weak_int_array_method_slot<T, F, E>(func: F, obj: Weak<Mutex<T>>) -> Slot
+    where T: Any + Send + Sync, F: Fn(&mut T, Vec<i32>) -> Result<(), E> + Send + Sync + 'static, E: Into<SlotError> {
+    Slot::FnIntArrayMethod((Receiver::weak(obj), method_arg(func)))
+}
+
+pub fn weak_float_method_slot<T, F, E>(func: F, obj: Weak<Mutex<T>>) -> Slot
+    where T: Any + Send + Sync, F: Fn(&mut T, f32) -> Result<(), E> + Send + Sync + 'static, E: Into<SlotError> {
+    Slot::FnFloatMethod((Receiver::weak(obj), method_arg(func)))
+}
+
+pub fn weak_float_array_method_slot<T, F, E>(func: F, obj: Weak<Mutex<T>>) -> Slot
+    where T: Any + Send + Sync, F: Fn(&mut T, Vec<f32>) -> Result<(), E> + Send + Sync + 'static, E: Into<SlotError> {
+    Slot::FnFloatArrayMethod((Receiver::weak(obj), method_arg(func)))
+}
+
+pub fn weak_bool_method_slot<T, F, E>(func: F, obj: Weak<Mutex<T>>) -> Slot
+    where T: Any + Send + Sync, F: Fn(&mut T, bool) -> Result<(), E> + Send + Sync + 'static, E: Into<SlotError> {
+    Slot::FnBoolMethod((Receiver::weak(obj), method_arg(func)))
+}
+
+pub fn weak_bool_array_method_slot<T, F, E>(func: F, obj: Weak<Mutex<T>>) -> Slot
+    where T: Any + Send + Sync, F: Fn(&mut T, Vec<bool>) -> Result<(), E> + Send + Sync + 'static, E: Into<SlotError> {
+    Slot::FnBoolArrayMethod((Receiver::weak(obj), method_arg(func)))
+}
+
+pub fn weak_string_method_slot<T, F, E>(func: F, obj: Weak<Mutex<T>>) -> Slot
+    where T: Any + Send + Sync, F: Fn(&mut T, String) -> Result<(), E> + Send + Sync + 'static, E: Into<SlotError> {
+    Slot::FnStringMethod((Receiver::weak(obj), method_arg(func)))
+}
+
+pub fn weak_string_array_method_slot<T, F, E>(func: F, obj: Weak<Mutex<T>>) -> Slot
+    where T: Any + Send + Sync, F: Fn(&mut T, Vec<String>) -> Result<(), E> + Send + Sync + 'static, E: Into<SlotError> {
+    Slot::FnStringArrayMethod((Receiver::weak(obj), method_arg(func)))
+}
+
+// returning variants, their values are collected with `SignalAndSlotHandler::emit_collect`
+
+/// A slot taking an `A` (`()` for `SlotArgs::None`) and returning an `R`.
+pub fn returning_slot<A, R, F, E>(func: F) -> Slot
+    where A: SlotValue, R: SlotValue, F: Fn(A) -> Result<R, E> + Send + Sync + 'static, E: Into<SlotError> {
+    Slot::FnReturn((A::KIND, Arc::new(move |args: SlotArgs| {
+        match A::from_args(args) {
+            Some(arg) => func(arg).map(R::into_args).map_err(Into::into),
+            None => Err(SlotError::Downcast { expected: std::any::type_name::<A>() }),
+        }
+    })))
+}
+
+pub fn returning_method_slot<T, A, R, F, E>(func: F, obj: Arc<Mutex<T>>) -> Slot
+    where T: Any + Send + Sync, A: SlotValue, R: SlotValue, F: Fn(&mut T, A) -> Result<R, E> + Send + Sync + 'static, E: Into<SlotError> {
+    Slot::FnReturnMethod((Receiver::strong(obj), A::KIND, method_return(func)))
+}
+
+pub fn weak_returning_method_slot<T, A, R, F, E>(func: F, obj: Weak<Mutex<T>>) -> Slot
+    where T: Any + Send + Sync, A: SlotValue, R: SlotValue, F: Fn(&mut T, A) -> Result<R, E> + Send + Sync + 'static, E: Into<SlotError> {
+    Slot::FnReturnMethod((Receiver::weak(obj), A::KIND, method_return(func)))
+}
+
+fn function_arg<D, F, E>(func: F) -> Arc<dyn Fn(D) -> Result<(), SlotError> + Send + Sync + 'static>
+    where D: 'static, F: Fn(D) -> Result<(), E> + Send + Sync + 'static, E: Into<SlotError> {
+    Arc::new(move |data: D| func(data).map_err(Into::into))
+}
+
+fn method_none<T, F, E>(func: F) -> Arc<dyn Fn(&mut dyn Any) -> Result<(), SlotError> + Send + Sync + 'static>
+    where T: Any + Send + Sync, F: Fn(&mut T) -> Result<(), E> + Send + Sync + 'static, E: Into<SlotError> {
+    Arc::new(move |arg: &mut dyn Any| {
+        match arg.downcast_mut::<T>() {
+            Some(value) => func(value).map_err(Into::into),
+            None => Err(SlotError::Downcast { expected: std::any::type_name::<T>() }),
+        }
+    })
+}
+
+fn method_arg<T, D, F, E>(func: F) -> Arc<dyn Fn(&mut dyn Any, D) -> Result<(), SlotError> + Send + Sync + 'static>
+    where T: Any + Send + Sync, D: 'static, F: Fn(&mut T, D) -> Result<(), E> + Send + Sync + 'static, E: Into<SlotError> {
+    Arc::new(move |arg: &mut dyn Any, data: D| {
+        match arg.downcast_mut::<T>() {
+            Some(value) => func(value, data).map_err(Into::into),
+            None => Err(SlotError::Downcast { expected: std::any::type_name::<T>() }),
+        }
+    })
+}
+
+fn method_return<T, A, R, F, E>(func: F) -> Arc<dyn Fn(&mut dyn Any, SlotArgs) -> Result<SlotArgs, SlotError> + Send + Sync + 'static>
+    where T: Any + Send + Sync, A: SlotValue, R: SlotValue, F: Fn(&mut T, A) -> Result<R, E> + Send + Sync + 'static, E: Into<SlotError> {
+    Arc::new(move |arg: &mut dyn Any, args: SlotArgs| {
+        let value = arg.downcast_mut::<T>().ok_or(SlotError::Downcast { expected: std::any::type_name::<T>() })?;
+        let data = A::from_args(args).ok_or(SlotError::Downcast { expected: std::any::type_name::<A>() })?;
+        func(value, data).map(R::into_args).map_err(Into::into)
+    })
 }
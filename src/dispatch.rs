@@ -0,0 +1,56 @@
+use std::sync::Mutex;
+use std::thread::{self, JoinHandle};
+
+use super::event_loop::{EventLoop, LoopHandle};
+
+pub(crate) type Task = Box<dyn FnOnce() + Send>;
+
+/// Background thread running the event loop that `Queued` and `BlockingQueued`
+/// slots go to when their connection has no loop of its own.
+pub(crate) struct Dispatcher {
+    handle: LoopHandle,
+    thread: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl Dispatcher {
+    pub(crate) fn start() -> Dispatcher {
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let thread = thread::Builder::new()
+            .name(String::from("sigs_slots-dispatcher"))
+            .spawn(move || {
+                let event_loop = EventLoop::new();
+                let _ = sender.send(event_loop.handle());
+                event_loop.run();
+            })
+            .expect("couldn't spawn dispatcher thread");
+        Dispatcher {
+            handle: receiver.recv().expect("dispatcher thread didn't start"),
+            thread: Mutex::new(Some(thread)),
+        }
+    }
+
+    pub(crate) fn handle(&self) -> &LoopHandle {
+        &self.handle
+    }
+
+    /// Waits until every posted task has run and stops the dispatcher thread.
+    pub(crate) fn shutdown(&self) {
+        self.handle.quit();
+        let thread = match self.thread.lock() {
+            Ok(mut guard) => guard.take(),
+            Err(poisoned) => poisoned.into_inner().take(),
+        };
+        if let Some(thread) = thread {
+            if !self.handle.is_current() {
+                let _ = thread.join();
+            }
+        }
+    }
+}
+
+//the loop keeps a sender to itself, so its thread only ends on quit
+impl Drop for Dispatcher {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
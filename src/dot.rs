@@ -0,0 +1,70 @@
+use std::fmt::Write;
+
+use super::{ConnectionInfo, ConnectionType, Signal};
+
+/// Renders signals, slots and receivers as a Graphviz DOT digraph.
+pub(crate) fn render(signals: &[Signal], connections: &[ConnectionInfo]) -> String {
+    let mut dot = String::from("digraph signals {\n    rankdir=LR;\n");
+
+    //forward targets without connections or declarations of their own still need a node
+    let mut nodes = signals.to_vec();
+    nodes.extend(connections.iter().filter_map(|info| info.forward_to));
+    nodes.sort();
+    nodes.dedup();
+    for signal in &nodes {
+        let _ = writeln!(dot, "    \"signal:{}\" [shape=ellipse, label=\"{}\"];", escape(signal.name()), escape(signal.name()));
+    }
+
+    let mut receivers = vec![];
+    for info in connections {
+        if let Some(to) = info.forward_to {
+            let label = match &info.label {
+                Some(label) => escape(label),
+                None => format!("forward {}", info.id),
+            };
+            let style = match (info.enabled, info.mapped) {
+                (false, _) => "dashed, color=gray",
+                (true, true) => "bold",
+                (true, false) => "solid",
+            };
+            let _ = writeln!(dot, "    \"signal:{}\" -> \"signal:{}\" [style={}, label=\"{}\"];",
+                escape(info.signal.name()), escape(to.name()), style, label);
+            continue;
+        }
+
+        let arg_kind = info.arg_kind.map(|kind| kind.to_string()).unwrap_or_default();
+        let label = match &info.label {
+            Some(label) => format!("{}\\n({})", escape(label), arg_kind),
+            None => format!("slot {}\\n({})", info.id, arg_kind),
+        };
+        let disabled = if info.enabled { "" } else { ", style=dashed, color=gray" };
+        let _ = writeln!(dot, "    \"slot:{}\" [shape=box, label=\"{}\"{}];", info.id, label, disabled);
+
+        let mut edge = String::new();
+        if info.connection_type != ConnectionType::Spawned {
+            edge.push_str(&format!("{:?}", info.connection_type));
+        }
+        if info.on_event_loop {
+            edge.push_str(" on loop");
+        }
+        let _ = writeln!(dot, "    \"signal:{}\" -> \"slot:{}\" [label=\"{}\"];", escape(info.signal.name()), info.id, edge.trim());
+
+        if let Some(receiver) = &info.receiver {
+            if !receivers.contains(&receiver.address) {
+                receivers.push(receiver.address);
+                let color = if receiver.alive { "black" } else { "gray" };
+                let _ = writeln!(dot, "    \"receiver:{:#x}\" [shape=component, color={}, label=\"{}\\n{:#x}\"];",
+                    receiver.address, color, escape(receiver.type_name), receiver.address);
+            }
+            let style = if receiver.weak { "dotted" } else { "dashed" };
+            let _ = writeln!(dot, "    \"slot:{}\" -> \"receiver:{:#x}\" [style={}, arrowhead=none];", info.id, receiver.address, style);
+        }
+    }
+
+    dot.push_str("}\n");
+    dot
+}
+
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
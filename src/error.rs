@@ -0,0 +1,175 @@
+use std::error::Error;
+use std::fmt;
+
+use super::{ArgKind, ConnectionId, ConnectionType, Signal};
+
+/// A connected slot whose argument kind did not match the emitted `SlotArgs`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArgMismatch {
+    pub connection: ConnectionId,
+    pub expected: ArgKind,
+    pub received: ArgKind,
+}
+
+impl fmt::Display for ArgMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "slot {} expects {} but received {}", self.connection, self.expected, self.received)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EmitError {
+    /// Raised in `MismatchPolicy::Strict` before any slot of the signal runs.
+    ArgMismatch {
+        signal: Signal,
+        mismatches: Vec<ArgMismatch>,
+    },
+    /// The signal was declared with a different argument kind than was emitted.
+    ArgKind {
+        signal: Signal,
+        declared: ArgKind,
+        received: ArgKind,
+    },
+}
+
+impl fmt::Display for EmitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EmitError::ArgMismatch { signal, mismatches } => {
+                write!(f, "argument mismatch on signal '{}'", signal)?;
+                for mismatch in mismatches {
+                    write!(f, "; {}", mismatch)?;
+                }
+                Ok(())
+            }
+            EmitError::ArgKind { signal, declared, received } => {
+                write!(f, "signal '{}' is declared with {} but was emitted with {}", signal, declared, received)
+            }
+        }
+    }
+}
+
+impl Error for EmitError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConnectError {
+    /// The slot doesn't accept the argument kind the signal was declared with.
+    ArgKind {
+        signal: Signal,
+        declared: ArgKind,
+        slot: ArgKind,
+    },
+    /// The signal is already declared with a different argument kind.
+    Redeclared {
+        signal: Signal,
+        declared: ArgKind,
+        requested: ArgKind,
+    },
+    /// A slot bound to an event loop must be `Queued` or `BlockingQueued`,
+    /// other connection types would run it on the wrong thread.
+    NotQueued {
+        connection_type: ConnectionType,
+    },
+    /// Forwarding `from` to `to` would close a loop of forwards.
+    Cycle {
+        from: Signal,
+        to: Signal,
+    },
+    /// An unmapped forward would connect signals declared with different argument kinds.
+    ForwardKind {
+        from: Signal,
+        from_kind: ArgKind,
+        to: Signal,
+        to_kind: ArgKind,
+    },
+    /// `connect_n` was asked for a slot that may run zero times.
+    ZeroShots,
+}
+
+impl fmt::Display for ConnectError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConnectError::ArgKind { signal, declared, slot } => {
+                write!(f, "signal '{}' is declared with {} but the slot takes {}", signal, declared, slot)
+            }
+            ConnectError::Redeclared { signal, declared, requested } => {
+                write!(f, "signal '{}' is already declared with {}, not {}", signal, declared, requested)
+            }
+            ConnectError::NotQueued { connection_type } => {
+                write!(f, "{:?} connections don't run on an event loop, use Queued or BlockingQueued", connection_type)
+            }
+            ConnectError::Cycle { from, to } => {
+                write!(f, "forwarding '{}' to '{}' would create a cycle", from, to)
+            }
+            ConnectError::ForwardKind { from, from_kind, to, to_kind } => {
+                write!(f, "can't forward '{}' ({}) to '{}' ({}) without a mapping", from, from_kind, to, to_kind)
+            }
+            ConnectError::ZeroShots => write!(f, "a slot connected for zero emits would never run"),
+        }
+    }
+}
+
+impl Error for ConnectError {}
+
+/// Error produced by a slot, or by the handler while running one.
+#[derive(Debug)]
+pub enum SlotError {
+    /// The receiver of a method slot was not of the type the slot was created for.
+    Downcast { expected: &'static str },
+    /// The slot itself returned an error.
+    User(Box<dyn Error + Send + Sync>),
+    /// The slot panicked, with the panic message if it had one.
+    Panic(String),
+    /// The slot did not finish in the time it was given.
+    Timeout,
+    /// The slot or its receiver sits behind a poisoned lock.
+    Poisoned,
+    /// The event loop the slot was posted to was dropped before running it.
+    LoopClosed,
+}
+
+impl SlotError {
+    pub fn user<E: Error + Send + Sync + 'static>(error: E) -> SlotError {
+        SlotError::User(Box::new(error))
+    }
+}
+
+impl fmt::Display for SlotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SlotError::Downcast { expected } => write!(f, "couldn't cast receiver to {}", expected),
+            SlotError::User(e) => write!(f, "{}", e),
+            SlotError::Panic(message) => write!(f, "slot panicked: {}", message),
+            SlotError::Timeout => write!(f, "slot timed out"),
+            SlotError::Poisoned => write!(f, "slot lock is poisoned"),
+            SlotError::LoopClosed => write!(f, "event loop closed before the slot ran"),
+        }
+    }
+}
+
+impl Error for SlotError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SlotError::User(e) => Some(e.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<Box<dyn Error + Send + Sync>> for SlotError {
+    fn from(error: Box<dyn Error + Send + Sync>) -> Self {
+        SlotError::User(error)
+    }
+}
+
+impl From<String> for SlotError {
+    fn from(error: String) -> Self {
+        SlotError::User(error.into())
+    }
+}
+
+impl From<&str> for SlotError {
+    fn from(error: &str) -> Self {
+        SlotError::User(error.into())
+    }
+}
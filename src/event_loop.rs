@@ -0,0 +1,113 @@
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, ThreadId};
+
+use super::dispatch::Task;
+
+enum Event {
+    Task(Task),
+    Quit,
+}
+
+/// A queue of slots that is run by one thread, the way Qt ties objects to a thread.
+///
+/// Connect with `SignalAndSlotHandler::connect_on` and the slot always runs on
+/// whichever thread calls `run` or `process_events` on this loop.
+pub struct EventLoop {
+    receiver: Receiver<Event>,
+    handle: LoopHandle,
+}
+
+/// Cheap, cloneable reference to an `EventLoop` that can post to it from any thread.
+#[derive(Clone)]
+pub struct LoopHandle {
+    sender: Sender<Event>,
+    owner: Arc<Mutex<Option<ThreadId>>>,
+}
+
+impl Default for EventLoop {
+    fn default() -> Self {
+        let (sender, receiver) = mpsc::channel();
+        EventLoop {
+            receiver,
+            handle: LoopHandle {
+                sender,
+                owner: Arc::new(Mutex::new(None)),
+            },
+        }
+    }
+}
+
+impl EventLoop {
+    pub fn new() -> EventLoop {
+        EventLoop::default()
+    }
+
+    pub fn handle(&self) -> LoopHandle {
+        self.handle.clone()
+    }
+
+    /// Runs every event that is queued right now and returns how many ran.
+    ///
+    /// Events posted while processing are left for the next call.
+    pub fn process_events(&self) -> usize {
+        self.claim();
+        let events = self.receiver.try_iter().collect::<Vec<Event>>();
+        let mut count = 0;
+        for event in events {
+            if let Event::Task(task) = event {
+                task();
+                count += 1;
+            }
+        }
+        count
+    }
+
+    /// Runs events as they arrive until `LoopHandle::quit` is called.
+    pub fn run(&self) {
+        self.claim();
+        for event in self.receiver.iter() {
+            match event {
+                Event::Task(task) => task(),
+                Event::Quit => return,
+            }
+        }
+    }
+
+    fn claim(&self) {
+        let current = Some(thread::current().id());
+        match self.handle.owner.lock() {
+            Ok(mut owner) => *owner = current,
+            Err(poisoned) => *poisoned.into_inner() = current,
+        }
+    }
+}
+
+impl LoopHandle {
+    /// Queues `task` on the loop. Gives the task back if the loop is gone.
+    pub(crate) fn post_task(&self, task: Task) -> Result<(), Task> {
+        self.sender.send(Event::Task(task)).map_err(|e| match e.0 {
+            Event::Task(task) => task,
+            Event::Quit => unreachable!(),
+        })
+    }
+
+    /// Queues a closure on the loop. Returns `false` if the loop is gone.
+    pub fn post<F: FnOnce() + Send + 'static>(&self, func: F) -> bool {
+        self.post_task(Box::new(func)).is_ok()
+    }
+
+    /// Makes `EventLoop::run` return once the events queued before this are done.
+    pub fn quit(&self) {
+        let _ = self.sender.send(Event::Quit);
+    }
+
+    /// Whether the calling thread is the one running this loop. `false` until the loop first runs.
+    pub fn is_current(&self) -> bool {
+        let owner = match self.owner.lock() {
+            Ok(owner) => *owner,
+            Err(poisoned) => *poisoned.into_inner(),
+        };
+        owner == Some(thread::current().id())
+    }
+}
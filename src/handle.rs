@@ -0,0 +1,158 @@
+use std::sync::mpsc::{Receiver, RecvTimeoutError, TryRecvError};
+use std::time::{Duration, Instant};
+
+use super::{ConnectionId, EmitReport, SlotError, SlotOutcome};
+
+/// A slot that was started by an emit but may not have finished yet.
+pub(crate) enum Pending {
+    Done(SlotOutcome),
+    Reply(Receiver<SlotOutcome>),
+    /// The emit a forward started on its target signal.
+    Nested(EmitHandle),
+}
+
+/// The task carrying the slot was dropped without running, only event loops do that.
+fn lost() -> SlotOutcome {
+    SlotOutcome::Failed(SlotError::LoopClosed)
+}
+
+impl Pending {
+    pub(crate) fn wait(&mut self) {
+        match self {
+            Pending::Done(_) => (),
+            Pending::Reply(receiver) => *self = Pending::Done(receiver.recv().unwrap_or_else(|_| lost())),
+            Pending::Nested(_) => self.finish_nested(EmitHandle::wait),
+        }
+    }
+
+    fn poll(&mut self) -> bool {
+        match self {
+            Pending::Done(_) => (),
+            Pending::Reply(receiver) => match receiver.try_recv() {
+                Ok(outcome) => *self = Pending::Done(outcome),
+                Err(TryRecvError::Disconnected) => *self = Pending::Done(lost()),
+                Err(TryRecvError::Empty) => return false,
+            },
+            Pending::Nested(handle) => {
+                if !handle.is_finished() {
+                    return false;
+                }
+                self.finish_nested(EmitHandle::wait);
+            }
+        }
+        true
+    }
+
+    fn wait_until(&mut self, deadline: Instant) -> bool {
+        match self {
+            Pending::Done(_) => (),
+            Pending::Reply(receiver) => {
+                let timeout = deadline.saturating_duration_since(Instant::now());
+                match receiver.recv_timeout(timeout) {
+                    Ok(outcome) => *self = Pending::Done(outcome),
+                    Err(RecvTimeoutError::Disconnected) => *self = Pending::Done(lost()),
+                    Err(RecvTimeoutError::Timeout) => return false,
+                }
+            }
+            Pending::Nested(handle) => {
+                if !handle.wait_timeout(deadline.saturating_duration_since(Instant::now())) {
+                    return false;
+                }
+                self.finish_nested(EmitHandle::wait);
+            }
+        }
+        true
+    }
+
+    /// Gives up on a slot that is still running.
+    fn time_out(&mut self) {
+        match self {
+            Pending::Done(_) => (),
+            Pending::Reply(_) => *self = Pending::Done(SlotOutcome::Failed(SlotError::Timeout)),
+            Pending::Nested(_) => self.finish_nested(EmitHandle::into_report),
+        }
+    }
+
+    fn finish_nested<F: FnOnce(EmitHandle) -> EmitReport>(&mut self, finish: F) {
+        if let Pending::Nested(handle) = std::mem::replace(self, Pending::Done(SlotOutcome::Queued)) {
+            *self = Pending::Done(SlotOutcome::Forwarded(finish(handle)));
+        }
+    }
+}
+
+/// Completion handle returned by `emit_async`.
+///
+/// Dropping the handle doesn't stop any slot, their results are just discarded.
+pub struct EmitHandle {
+    pending: Vec<(ConnectionId, Pending)>,
+    report: Option<EmitReport>,
+}
+
+impl EmitHandle {
+    pub(crate) fn new(pending: Vec<(ConnectionId, Pending)>) -> EmitHandle {
+        let mut handle = EmitHandle { pending, report: None };
+        handle.is_finished();
+        handle
+    }
+
+    /// The handle of an emit that was blocked, it started no slot.
+    pub(crate) fn blocked() -> EmitHandle {
+        EmitHandle { pending: vec![], report: Some(EmitReport::blocked()) }
+    }
+
+    /// Checks without blocking whether every slot has finished.
+    pub fn is_finished(&mut self) -> bool {
+        if self.report.is_none() && self.pending.iter_mut().all(|(_, pending)| pending.poll()) {
+            self.finish();
+        }
+        self.report.is_some()
+    }
+
+    /// Blocks until every slot has finished.
+    pub fn wait(mut self) -> EmitReport {
+        if self.report.is_none() {
+            for (_, pending) in self.pending.iter_mut() {
+                pending.wait();
+            }
+            self.finish();
+        }
+        self.report.take().unwrap_or_default()
+    }
+
+    /// Blocks until every slot has finished or `timeout` passed. Returns `true` if all finished.
+    pub fn wait_timeout(&mut self, timeout: Duration) -> bool {
+        let deadline = Instant::now() + timeout;
+        if self.report.is_none() && self.pending.iter_mut().all(|(_, pending)| pending.wait_until(deadline)) {
+            self.finish();
+        }
+        self.report.is_some()
+    }
+
+    /// The per-slot results, once every slot has finished.
+    pub fn report(&self) -> Option<&EmitReport> {
+        self.report.as_ref()
+    }
+
+    /// The results so far, without blocking. Slots still running are reported as `SlotError::Timeout`.
+    pub fn into_report(mut self) -> EmitReport {
+        if !self.is_finished() {
+            for (_, pending) in self.pending.iter_mut() {
+                if !pending.poll() {
+                    pending.time_out();
+                }
+            }
+            self.finish();
+        }
+        self.report.take().unwrap_or_default()
+    }
+
+    fn finish(&mut self) {
+        let mut report = EmitReport::default();
+        for (id, pending) in self.pending.drain(..) {
+            if let Pending::Done(outcome) = pending {
+                report.push(id, outcome);
+            }
+        }
+        self.report = Some(report);
+    }
+}
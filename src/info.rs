@@ -0,0 +1,50 @@
+use super::{ArgKind, ConnectionId, ConnectionType, Receiver, Signal, SlotKind};
+
+/// Description of a method slot's receiver.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReceiverInfo {
+    pub type_name: &'static str,
+    /// Equal for all connections bound to the same object.
+    pub address: usize,
+    pub weak: bool,
+    pub alive: bool,
+}
+
+impl From<&Receiver> for ReceiverInfo {
+    fn from(receiver: &Receiver) -> Self {
+        ReceiverInfo {
+            type_name: receiver.type_name(),
+            address: receiver.address(),
+            weak: receiver.is_weak(),
+            alive: receiver.is_alive(),
+        }
+    }
+}
+
+/// Snapshot of a single connection, for debugging the wiring at runtime.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConnectionInfo {
+    pub id: ConnectionId,
+    pub signal: Signal,
+    /// The `SlotArgs` variant the slot accepts, `None` for forwards.
+    pub arg_kind: Option<ArgKind>,
+    /// `None` for forwards.
+    pub slot_kind: Option<SlotKind>,
+    /// The signal this connection re-emits on, for connections made with `connect_forward`.
+    pub forward_to: Option<Signal>,
+    /// Whether a forward maps the arguments before re-emitting them.
+    pub mapped: bool,
+    /// `None` for function slots and forwards.
+    pub receiver: Option<ReceiverInfo>,
+    pub connection_type: ConnectionType,
+    /// Whether the slot is posted to an event loop of its own.
+    pub on_event_loop: bool,
+    /// Name given with `SignalAndSlotHandler::set_label`.
+    pub label: Option<String>,
+    /// Runs left for connections made with `connect_once` or `connect_n`.
+    pub shots_left: Option<usize>,
+    /// Set with `SignalAndSlotHandler::set_priority`.
+    pub priority: i32,
+    /// `false` after `SignalAndSlotHandler::set_enabled(id, false)`.
+    pub enabled: bool,
+}
@@ -1,40 +1,156 @@
+pub mod combiner;
+mod blocker;
 #[allow(clippy::type_complexity)]
 pub mod create;
+mod dispatch;
+mod dot;
+mod error;
+mod event_loop;
+mod handle;
+mod info;
+mod pool;
+mod propagation;
+mod report;
+mod scoped;
+mod signal_id;
+pub mod typed;
 
-use std::sync::{Mutex, Arc};
-use std::ops::{Deref, DerefMut};
+pub use blocker::SignalBlocker;
+pub use error::{ArgMismatch, ConnectError, EmitError, SlotError};
+pub use event_loop::{EventLoop, LoopHandle};
+pub use handle::EmitHandle;
+pub use info::{ConnectionInfo, ReceiverInfo};
+pub use pool::PoolConfig;
+pub use propagation::stop_propagation;
+pub use report::{EmitReport, SlotOutcome, SlotResult};
+pub use scoped::ScopedConnection;
+pub use signal_id::SignalId;
 
-use lazy_static::lazy_static;
+use std::sync::{Mutex, RwLock, RwLockReadGuard, RwLockWriteGuard, Arc, Weak, OnceLock};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::ops::DerefMut;
+use std::panic::{self, AssertUnwindSafe};
+
+use lazy_static::lazy_static; // 1.4.0
 use std::any::Any;
-use std::thread::JoinHandle; // 1.4.0
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use combiner::Combiner;
+use dispatch::{Dispatcher, Task};
+use handle::Pending;
+use pool::ThreadPool;
 
 lazy_static! {
     /// A Singleton for general SIGNAL handling.
-    pub static ref SON_SIGNAL: Mutex<SignalAndSlotHandler> = Mutex::new(SignalAndSlotHandler::new());
+    pub static ref SON_SIGNAL: SignalAndSlotHandler = SignalAndSlotHandler::new();
 }
 
 
-pub type Signal = String;
+pub type Signal = SignalId;
+
+pub type FnNone = Arc<dyn Fn() -> Result<(), SlotError> + Send + Sync>;
+pub type FnInt = Arc<dyn Fn(i32) -> Result<(), SlotError> + Send + Sync>;
+pub type FnIntArray = Arc<dyn Fn(Vec<i32>) -> Result<(), SlotError> + Send + Sync>;
+pub type FnFloat = Arc<dyn Fn(f32) -> Result<(), SlotError> + Send + Sync>;
+pub type FnFloatArray = Arc<dyn Fn(Vec<f32>) -> Result<(), SlotError> + Send + Sync>;
+pub type FnBool = Arc<dyn Fn(bool) -> Result<(), SlotError> + Send + Sync>;
+pub type FnBoolArray = Arc<dyn Fn(Vec<bool>) -> Result<(), SlotError> + Send + Sync>;
+pub type FnString = Arc<dyn Fn(String) -> Result<(), SlotError> + Send + Sync>;
+pub type FnStringArray = Arc<dyn Fn(Vec<String>) -> Result<(), SlotError> + Send + Sync>;
 
-pub type FnNone = Arc<Mutex<dyn Fn() -> Result<(), String> + Send + Sync>>;
-pub type FnInt = Arc<Mutex<dyn Fn(i32) -> Result<(), String> + Send + Sync>>;
-pub type FnIntArray = Arc<Mutex<dyn Fn(Vec<i32>) -> Result<(), String> + Send + Sync>>;
-pub type FnFloat = Arc<Mutex<dyn Fn(f32) -> Result<(), String> + Send + Sync>>;
-pub type FnFloatArray = Arc<Mutex<dyn Fn(Vec<f32>) -> Result<(), String> + Send + Sync>>;
-pub type FnBool = Arc<Mutex<dyn Fn(bool) -> Result<(), String> + Send + Sync>>;
-pub type FnBoolArray = Arc<Mutex<dyn Fn(Vec<bool>) -> Result<(), String> + Send + Sync>>;
-pub type FnString = Arc<Mutex<dyn Fn(String) -> Result<(), String> + Send + Sync>>;
-pub type FnStringArray = Arc<Mutex<dyn Fn(Vec<String>) -> Result<(), String> + Send + Sync>>;
+/// Rewrites the arguments of a forwarded emit, see `SignalAndSlotHandler::connect_forward_map`.
+pub type ArgMap = Arc<dyn Fn(SlotArgs) -> SlotArgs + Send + Sync>;
 
-pub type FnNoneMethod = (Arc<Mutex<dyn Any + Send + Sync>>, Arc<Mutex<dyn Fn(&mut dyn Any) -> Result<(), String> + Send + Sync + 'static>>);
-pub type FnIntMethod = (Arc<Mutex<dyn Any + Send + Sync>>, Arc<Mutex<dyn Fn(&mut dyn Any, i32) -> Result<(), String> + Send + Sync + 'static>>);
-pub type FnIntArrayMethod = (Arc<Mutex<dyn Any + Send + Sync>>, Arc<Mutex<dyn Fn(&mut dyn Any, Vec<i32>) -> Result<(), String> + Send + Sync + 'static>>);
-pub type FnFloatMethod = (Arc<Mutex<dyn Any + Send + Sync>>, Arc<Mutex<dyn Fn(&mut dyn Any, f32) -> Result<(), String> + Send + Sync + 'static>>);
-pub type FnFloatArrayMethod = (Arc<Mutex<dyn Any + Send + Sync>>, Arc<Mutex<dyn Fn(&mut dyn Any, Vec<f32>) -> Result<(), String> + Send + Sync + 'static>>);
-pub type FnBoolMethod = (Arc<Mutex<dyn Any + Send + Sync>>, Arc<Mutex<dyn Fn(&mut dyn Any, bool) -> Result<(), String> + Send + Sync + 'static>>);
-pub type FnBoolArrayMethod = (Arc<Mutex<dyn Any + Send + Sync>>, Arc<Mutex<dyn Fn(&mut dyn Any, Vec<bool>) -> Result<(), String> + Send + Sync + 'static>>);
-pub type FnStringMethod = (Arc<Mutex<dyn Any + Send + Sync>>, Arc<Mutex<dyn Fn(&mut dyn Any, String) -> Result<(), String> + Send + Sync + 'static>>);
-pub type FnStringArrayMethod = (Arc<Mutex<dyn Any + Send + Sync>>, Arc<Mutex<dyn Fn(&mut dyn Any, Vec<String>) -> Result<(), String> + Send + Sync + 'static>>);
+/// A slot returning a value, with the kind of `SlotArgs` it accepts.
+pub type FnReturn = (ArgKind, Arc<dyn Fn(SlotArgs) -> Result<SlotArgs, SlotError> + Send + Sync>);
+
+pub type FnNoneMethod = (Receiver, Arc<dyn Fn(&mut dyn Any) -> Result<(), SlotError> + Send + Sync + 'static>);
+pub type FnIntMethod = (Receiver, Arc<dyn Fn(&mut dyn Any, i32) -> Result<(), SlotError> + Send + Sync + 'static>);
+pub type FnIntArrayMethod = (Receiver, Arc<dyn Fn(&mut dyn Any, Vec<i32>) -> Result<(), SlotError> + Send + Sync + 'static>);
+pub type FnFloatMethod = (Receiver, Arc<dyn Fn(&mut dyn Any, f32) -> Result<(), SlotError> + Send + Sync + 'static>);
+pub type FnFloatArrayMethod = (Receiver, Arc<dyn Fn(&mut dyn Any, Vec<f32>) -> Result<(), SlotError> + Send + Sync + 'static>);
+pub type FnBoolMethod = (Receiver, Arc<dyn Fn(&mut dyn Any, bool) -> Result<(), SlotError> + Send + Sync + 'static>);
+pub type FnBoolArrayMethod = (Receiver, Arc<dyn Fn(&mut dyn Any, Vec<bool>) -> Result<(), SlotError> + Send + Sync + 'static>);
+pub type FnStringMethod = (Receiver, Arc<dyn Fn(&mut dyn Any, String) -> Result<(), SlotError> + Send + Sync + 'static>);
+pub type FnStringArrayMethod = (Receiver, Arc<dyn Fn(&mut dyn Any, Vec<String>) -> Result<(), SlotError> + Send + Sync + 'static>);
+pub type FnReturnMethod = (Receiver, ArgKind, Arc<dyn Fn(&mut dyn Any, SlotArgs) -> Result<SlotArgs, SlotError> + Send + Sync + 'static>);
+
+/// The object a method slot is called on.
+///
+/// A weak receiver does not keep the object alive; once it is dropped the
+/// slot is skipped on emit and pruned by the handler.
+#[derive(Clone)]
+pub struct Receiver {
+    object: ReceiverRef,
+    type_name: &'static str,
+}
+
+#[derive(Clone)]
+enum ReceiverRef {
+    Strong(Arc<Mutex<dyn Any + Send + Sync>>),
+    Weak(Weak<Mutex<dyn Any + Send + Sync>>),
+}
+
+impl Receiver {
+    pub fn strong<T: Any + Send + Sync>(obj: Arc<Mutex<T>>) -> Receiver {
+        Receiver {
+            object: ReceiverRef::Strong(obj),
+            type_name: std::any::type_name::<T>(),
+        }
+    }
+
+    pub fn weak<T: Any + Send + Sync>(obj: Weak<Mutex<T>>) -> Receiver {
+        Receiver {
+            object: ReceiverRef::Weak(obj),
+            type_name: std::any::type_name::<T>(),
+        }
+    }
+
+    pub fn upgrade(&self) -> Option<Arc<Mutex<dyn Any + Send + Sync>>> {
+        match &self.object {
+            ReceiverRef::Strong(obj) => Some(Arc::clone(obj)),
+            ReceiverRef::Weak(obj) => obj.upgrade(),
+        }
+    }
+
+    pub fn is_alive(&self) -> bool {
+        match &self.object {
+            ReceiverRef::Strong(_) => true,
+            ReceiverRef::Weak(obj) => obj.strong_count() > 0,
+        }
+    }
+
+    pub fn is_weak(&self) -> bool {
+        matches!(self.object, ReceiverRef::Weak(_))
+    }
+
+    /// Type name of the object, as given by `std::any::type_name`.
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// Address of the object, equal for all slots bound to the same object.
+    pub fn address(&self) -> usize {
+        self.as_ptr() as usize
+    }
+
+    fn as_ptr(&self) -> *const u8 {
+        match &self.object {
+            ReceiverRef::Strong(obj) => Arc::as_ptr(obj) as *const u8,
+            ReceiverRef::Weak(obj) => Weak::as_ptr(obj) as *const u8,
+        }
+    }
+}
+
+/// Whether a slot is a free function or a method bound to a receiver.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SlotKind {
+    Function,
+    Method,
+}
 
 #[derive(Clone)]
 pub enum Slot {
@@ -57,10 +173,14 @@ pub enum Slot {
     FnBoolArrayMethod(FnBoolArrayMethod),
     FnStringMethod(FnStringMethod),
     FnStringArrayMethod(FnStringArrayMethod),
+
+    /// Built with `create::returning_slot`, see `SignalAndSlotHandler::emit_collect`.
+    FnReturn(FnReturn),
+    FnReturnMethod(FnReturnMethod),
 }
 
 
-#[derive(Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum SlotArgs {
     None,
     Int(i32),
@@ -73,227 +193,1203 @@ pub enum SlotArgs {
     StringArray(Vec<String>),
 }
 
-pub struct SignalAndSlotHandler {
-    connections: Vec<(Signal, Slot)>,
+/// The `SlotArgs` variant a slot accepts or an emit carries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ArgKind {
+    None,
+    Int,
+    IntArray,
+    Float,
+    FloatArray,
+    Bool,
+    BoolArray,
+    String,
+    StringArray,
 }
 
-impl Default for SignalAndSlotHandler{
-    fn default() -> Self {
-        SignalAndSlotHandler {
-            connections: vec![],
+impl fmt::Display for ArgKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+impl SlotArgs {
+    pub fn kind(&self) -> ArgKind {
+        match self {
+            SlotArgs::None => ArgKind::None,
+            SlotArgs::Int(_) => ArgKind::Int,
+            SlotArgs::IntArray(_) => ArgKind::IntArray,
+            SlotArgs::Float(_) => ArgKind::Float,
+            SlotArgs::FloatArray(_) => ArgKind::FloatArray,
+            SlotArgs::Bool(_) => ArgKind::Bool,
+            SlotArgs::BoolArray(_) => ArgKind::BoolArray,
+            SlotArgs::String(_) => ArgKind::String,
+            SlotArgs::StringArray(_) => ArgKind::StringArray,
+        }
+    }
+}
+
+/// A Rust type carried by one `SlotArgs` variant, `()` standing for `SlotArgs::None`.
+pub trait SlotValue: Sized {
+    const KIND: ArgKind;
+
+    fn into_args(self) -> SlotArgs;
+
+    /// `None` if `args` is another variant.
+    fn from_args(args: SlotArgs) -> Option<Self>;
+}
+
+macro_rules! slot_value {
+    ($($type:ty => $variant:ident),* $(,)?) => {
+        $(
+            impl SlotValue for $type {
+                const KIND: ArgKind = ArgKind::$variant;
+
+                fn into_args(self) -> SlotArgs {
+                    SlotArgs::$variant(self)
+                }
+
+                fn from_args(args: SlotArgs) -> Option<Self> {
+                    match args {
+                        SlotArgs::$variant(value) => Some(value),
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+slot_value! {
+    i32 => Int,
+    Vec<i32> => IntArray,
+    f32 => Float,
+    Vec<f32> => FloatArray,
+    bool => Bool,
+    Vec<bool> => BoolArray,
+    String => String,
+    Vec<String> => StringArray,
+}
+
+impl SlotValue for () {
+    const KIND: ArgKind = ArgKind::None;
+
+    fn into_args(self) -> SlotArgs {
+        SlotArgs::None
+    }
+
+    fn from_args(args: SlotArgs) -> Option<Self> {
+        match args {
+            SlotArgs::None => Some(()),
+            _ => None,
+        }
+    }
+}
+
+/// What `emit` does with slots whose argument kind doesn't match the emitted `SlotArgs`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MismatchPolicy {
+    /// Skip them silently.
+    #[default]
+    Ignore,
+    /// Skip them and log which slot was skipped to stderr.
+    Lenient,
+    /// Run no slot at all and return `EmitError::ArgMismatch`.
+    Strict,
+}
+
+impl Slot {
+    /// The `SlotArgs` variant this slot accepts.
+    pub fn arg_kind(&self) -> ArgKind {
+        use self::Slot::*;
+        match self {
+            FnNone(_) | FnNoneMethod(_) => ArgKind::None,
+            FnInt(_) | FnIntMethod(_) => ArgKind::Int,
+            FnIntArray(_) | FnIntArrayMethod(_) => ArgKind::IntArray,
+            FnFloat(_) | FnFloatMethod(_) => ArgKind::Float,
+            FnFloatArray(_) | FnFloatArrayMethod(_) => ArgKind::FloatArray,
+            FnBool(_) | FnBoolMethod(_) => ArgKind::Bool,
+            FnBoolArray(_) | FnBoolArrayMethod(_) => ArgKind::BoolArray,
+            FnString(_) | FnStringMethod(_) => ArgKind::String,
+            FnStringArray(_) | FnStringArrayMethod(_) => ArgKind::StringArray,
+            FnReturn((kind, _)) | FnReturnMethod((_, kind, _)) => *kind,
+        }
+    }
+
+    pub fn kind(&self) -> SlotKind {
+        match self.receiver() {
+            Some(_) => SlotKind::Method,
+            None => SlotKind::Function,
+        }
+    }
+
+    pub fn is_method(&self) -> bool {
+        self.kind() == SlotKind::Method
+    }
+
+    /// The object a method slot is bound to, `None` for plain function slots.
+    pub fn receiver(&self) -> Option<&Receiver> {
+        use self::Slot::*;
+        match self {
+            FnNoneMethod((obj, _)) | FnIntMethod((obj, _)) | FnIntArrayMethod((obj, _))
+            | FnFloatMethod((obj, _)) | FnFloatArrayMethod((obj, _)) | FnBoolMethod((obj, _))
+            | FnBoolArrayMethod((obj, _)) | FnStringMethod((obj, _)) | FnStringArrayMethod((obj, _))
+            | FnReturnMethod((obj, _, _)) => Some(obj),
+            _ => None,
+        }
+    }
+
+    /// Whether this is a method slot bound to `obj`.
+    pub fn targets<T: Any + Send + Sync>(&self, obj: &Arc<Mutex<T>>) -> bool {
+        match self.receiver() {
+            Some(receiver) => receiver.as_ptr() == Arc::as_ptr(obj) as *const u8,
+            None => false,
+        }
+    }
+}
+
+static NEXT_CONNECTION_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Handle to a single signal-slot connection, returned by `connect`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ConnectionId(u64);
+
+impl ConnectionId {
+    fn next() -> ConnectionId {
+        ConnectionId(NEXT_CONNECTION_ID.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+impl fmt::Display for ConnectionId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{}", self.0)
+    }
+}
+
+/// What happens to emits of a blocked signal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BlockPolicy {
+    /// They are dropped without running any slot.
+    #[default]
+    Drop,
+    /// They are held back and emitted again, in order, once the signal is unblocked.
+    Replay,
+}
+
+#[derive(Default)]
+struct Blocking {
+    all: bool,
+    signals: HashSet<Signal>,
+    policy: BlockPolicy,
+    //emits held back under `BlockPolicy::Replay`, oldest first
+    held: Vec<(Signal, SlotArgs)>,
+}
+
+impl Blocking {
+    fn is_blocked(&self, signal: Signal) -> bool {
+        self.all || self.signals.contains(&signal)
+    }
+}
+
+/// Whether the slots of one emit run at the same time or one after another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DispatchMode {
+    /// Starts every slot before waiting for any of them.
+    #[default]
+    Parallel,
+    /// Waits for each slot to finish before starting the next, in priority
+    /// order. `emit_async` then only returns once all slots are done, except
+    /// `Queued` ones, which are never waited for.
+    Sequential,
+}
+
+/// How a connected slot is invoked when its signal is emitted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConnectionType {
+    /// Runs inline on the emitting thread.
+    Direct,
+    /// Posted to the connection's event loop, or the handler's dispatcher
+    /// thread if it has none. `emit` doesn't wait for it.
+    Queued,
+    /// Like `Queued`, but `emit` waits for it to finish.
+    BlockingQueued,
+    /// Runs on a worker of the handler's pool, or on its own thread if the
+    /// handler has no pool. `emit` waits for it before returning.
+    #[default]
+    Spawned,
+}
+
+#[derive(Clone)]
+struct Connection {
+    id: ConnectionId,
+    action: Action,
+    connection_type: ConnectionType,
+    target: Option<LoopHandle>,
+    label: Option<String>,
+    //runs left for `connect_n`, shared by every copy of the connection list
+    shots: Option<Arc<AtomicUsize>>,
+    priority: i32,
+    enabled: bool,
+}
+
+/// What a connection does when its signal is emitted.
+#[derive(Clone)]
+enum Action {
+    Slot(Slot),
+    /// Emits `to` with the same, or mapped, arguments.
+    Forward { to: Signal, map: Option<ArgMap> },
+}
+
+impl Connection {
+    //lists are kept sorted by this: higher priority first, then connection order
+    fn order(&self) -> (Reverse<i32>, ConnectionId) {
+        (Reverse(self.priority), self.id)
+    }
+
+    fn slot(&self) -> Option<&Slot> {
+        match &self.action {
+            Action::Slot(slot) => Some(slot),
+            Action::Forward { .. } => None,
+        }
+    }
+
+    /// The target of an unmapped forward, whose arguments pass through unchanged.
+    fn passthrough(&self) -> Option<Signal> {
+        match &self.action {
+            Action::Forward { to, map: None } => Some(*to),
+            _ => None,
+        }
+    }
+
+    /// Uses up one run of a connection made with `connect_n`. Returns `false`
+    /// if none are left, e.g. because another thread emitted at the same time.
+    fn take_shot(&self) -> bool {
+        match &self.shots {
+            Some(shots) => shots.fetch_update(Ordering::AcqRel, Ordering::Acquire, |left| left.checked_sub(1)).is_ok(),
+            None => true,
+        }
+    }
+
+    //a disabled slot never runs, so it can't mismatch either
+    fn mismatch(&self, received: ArgKind) -> Option<ArgMismatch> {
+        if !self.enabled {
+            return None;
+        }
+        match self.slot().map(Slot::arg_kind) {
+            Some(expected) if expected != received => Some(ArgMismatch { connection: self.id, expected, received }),
+            _ => None,
+        }
+    }
+
+    /// Whether this is a method slot whose weak receiver has been dropped.
+    fn is_dead(&self) -> bool {
+        self.slot().and_then(Slot::receiver).is_some_and(|receiver| !receiver.is_alive())
+    }
+
+    fn is_spent(&self) -> bool {
+        self.shots.as_ref().is_some_and(|shots| shots.load(Ordering::Acquire) == 0)
+    }
+
+    fn info(&self, signal: Signal) -> ConnectionInfo {
+        let (forward_to, mapped) = match &self.action {
+            Action::Slot(_) => (None, false),
+            Action::Forward { to, map } => (Some(*to), map.is_some()),
+        };
+        ConnectionInfo {
+            id: self.id,
+            signal,
+            arg_kind: self.slot().map(Slot::arg_kind),
+            slot_kind: self.slot().map(Slot::kind),
+            forward_to,
+            mapped,
+            receiver: self.slot().and_then(Slot::receiver).map(ReceiverInfo::from),
+            connection_type: self.connection_type,
+            on_event_loop: self.target.is_some(),
+            label: self.label.clone(),
+            shots_left: self.shots.as_ref().map(|shots| shots.load(Ordering::Acquire)),
+            priority: self.priority,
+            enabled: self.enabled,
         }
     }
 }
 
+//`Some` carries the value of a returning slot
+type SlotJob = Box<dyn FnOnce() -> Result<Option<SlotArgs>, SlotError> + Send>;
+
+/// Connects signals to slots and emits them.
+///
+/// All methods take `&self`, so a handler can be shared as a static or in an
+/// `Arc` and used from many threads at once. Connections are kept per signal,
+/// so an emit only looks at the slots of its own signal. It takes a snapshot
+/// of that list and never runs slots under a lock; connecting copies the list
+/// if an emit still holds the old one. Slots may therefore emit, connect and
+/// disconnect through the same handler, e.g. `SON_SIGNAL`.
+#[derive(Default)]
+pub struct SignalAndSlotHandler {
+    connections: RwLock<HashMap<Signal, Arc<Vec<Connection>>>>,
+    //only locked after `connections` when both are needed
+    schemas: RwLock<HashMap<Signal, ArgKind>>,
+    pruned: AtomicUsize,
+    mismatch_policy: RwLock<MismatchPolicy>,
+    dispatch_mode: RwLock<DispatchMode>,
+    blocking: RwLock<Blocking>,
+    dispatcher: OnceLock<Dispatcher>,
+    pool: Option<ThreadPool>,
+}
+
 impl SignalAndSlotHandler {
     pub fn new() -> SignalAndSlotHandler {
         SignalAndSlotHandler::default()
     }
 
-    pub fn connect(&mut self, signal: Signal, slot: Slot) {
-        self.connections.push((signal, slot));
+    /// A handler that runs `Spawned` slots on a worker pool instead of a new thread per slot.
+    pub fn with_pool(config: PoolConfig) -> SignalAndSlotHandler {
+        SignalAndSlotHandler {
+            pool: Some(ThreadPool::new(config)),
+            ..SignalAndSlotHandler::default()
+        }
     }
 
-    pub fn emit(&self, signal: Signal, slot_args: SlotArgs) {
-        let slots = self.connections
-            .iter()
-            .filter(|(sig, _slot)| *sig == signal)
-            .map(|(_sig, slot)| slot.clone())
-            .collect::<Vec<Slot>>();
+    /// Waits for all queued and pooled slots to finish and stops the worker threads.
+    ///
+    /// Slots emitted afterwards run on a new thread each, queued ones inline.
+    pub fn shutdown(&self) {
+        if let Some(dispatcher) = self.dispatcher.get() {
+            dispatcher.shutdown();
+        }
+        if let Some(pool) = &self.pool {
+            pool.shutdown();
+        }
+    }
 
-        let mut handle_vector = vec![];
-        for slot in slots {
-            let slot_arg_temp = slot_args.clone();
-            use self::Slot::*;
-            use self::SlotArgs::*;
-            match (slot, slot_arg_temp) {
-                (FnNone(func_mtx), None) => {
-                    let func_mtx = clone_all_function_none(func_mtx);
-                    let handle = create_slot_function_none(func_mtx);
-                    handle_vector.push(handle);
-                }
-                (FnInt(func_mtx), Int(data)) => {
-                    let (func_mtx, data) = clone_all_function_arg(func_mtx, data);
-                    let handle = create_slot_function_arg(func_mtx, data);
-                    handle_vector.push(handle);
-                }
-                (FnIntArray(func_mtx), IntArray(data)) => {
-                    let (func_mtx, data) = clone_all_function_arg(func_mtx, data);
-                    let handle = create_slot_function_arg(func_mtx, data);
-                    handle_vector.push(handle);
-                }
-                (FnFloat(func_mtx), Float(data)) => {
-                    let (func_mtx, data) = clone_all_function_arg(func_mtx, data);
-                    let handle = create_slot_function_arg(func_mtx, data);
-                    handle_vector.push(handle);
-                }
-                (FnFloatArray(func_mtx), FloatArray(data)) => {
-                    let (func_mtx, data) = clone_all_function_arg(func_mtx, data);
-                    let handle = create_slot_function_arg(func_mtx, data);
-                    handle_vector.push(handle);
-                }
-                (FnBool(func_mtx), Bool(data)) => {
-                    let (func_mtx, data) = clone_all_function_arg(func_mtx, data);
-                    let handle = create_slot_function_arg(func_mtx, data);
-                    handle_vector.push(handle);
-                }
-                (FnBoolArray(func_mtx), BoolArray(data)) => {
-                    let (func_mtx, data) = clone_all_function_arg(func_mtx, data);
-                    let handle = create_slot_function_arg(func_mtx, data);
-                    handle_vector.push(handle);
-                }
-                (FnString(func_mtx), String(data)) => {
-                    let (func_mtx, data) = clone_all_function_arg(func_mtx, data);
-                    let handle = create_slot_function_arg(func_mtx, data);
-                    handle_vector.push(handle);
-                }
-                (FnStringArray(func_mtx), StringArray(data)) => {
-                    let (func_mtx, data) = clone_all_function_arg(func_mtx, data);
-                    let handle = create_slot_function_arg(func_mtx, data);
-                    handle_vector.push(handle);
-                }
-                (FnNoneMethod((obj_mtx, func_mtx)), None) => {
-                    let (func, mtx) = clone_all_method_none(func_mtx, obj_mtx);
-                    let handle = create_slot_method_none(func, mtx);
-                    handle_vector.push(handle);
+    /// Declares that `signal` carries `kind`. Afterwards slots taking another
+    /// kind can't be connected to it and emitting another kind fails.
+    ///
+    /// Declaring again with the same kind does nothing.
+    pub fn declare(&self, signal: impl Into<Signal>, kind: ArgKind) -> Result<(), ConnectError> {
+        let signal = signal.into();
+        let connections = read(&self.connections);
+        let mut schemas = write(&self.schemas);
+        if let Some(&declared) = schemas.get(&signal) {
+            if declared != kind {
+                return Err(ConnectError::Redeclared { signal, declared, requested: kind });
+            }
+            return Ok(());
+        }
+        let connected = connections.get(&signal).into_iter().flat_map(|list| list.iter());
+        for connection in connected {
+            match connection.slot().map(Slot::arg_kind) {
+                Some(slot) if slot != kind => return Err(ConnectError::ArgKind { signal, declared: kind, slot }),
+                _ => (),
+            }
+        }
+        //unmapped forwards in either direction must carry the same kind on both ends
+        for (&from, list) in connections.iter() {
+            for to in list.iter().filter_map(Connection::passthrough) {
+                let (from_kind, to_kind) = match (from == signal, to == signal) {
+                    (true, false) => (Some(kind), schemas.get(&to).copied()),
+                    (false, true) => (schemas.get(&from).copied(), Some(kind)),
+                    _ => continue,
+                };
+                if let (Some(from_kind), Some(to_kind)) = (from_kind, to_kind) {
+                    if from_kind != to_kind {
+                        return Err(ConnectError::ForwardKind { from, from_kind, to, to_kind });
+                    }
                 }
-                (Slot::FnIntMethod((obj_mtx, func_mtx)), Int(data)) => {
-                    let (func_mtx, obj_mtx, data) = clone_all_method_arg(func_mtx, obj_mtx, data);
-                    let handle = create_slot_method_arg(func_mtx, obj_mtx, data);
-                    handle_vector.push(handle);
+            }
+        }
+        schemas.insert(signal, kind);
+        Ok(())
+    }
+
+    /// The argument kind `signal` was declared with, if any.
+    pub fn declared_kind(&self, signal: impl Into<Signal>) -> Option<ArgKind> {
+        read(&self.schemas).get(&signal.into()).copied()
+    }
+
+    pub fn connect(&self, signal: impl Into<Signal>, slot: Slot) -> Result<ConnectionId, ConnectError> {
+        self.connect_with(signal, slot, ConnectionType::default())
+    }
+
+    pub fn connect_with(&self, signal: impl Into<Signal>, slot: Slot, connection_type: ConnectionType) -> Result<ConnectionId, ConnectError> {
+        self.push_connection(signal, slot, connection_type, None, None)
+    }
+
+    /// Connects a slot that always runs on the thread driving `target`.
+    ///
+    /// `connection_type` must be `Queued` or `BlockingQueued`, other types
+    /// don't go through an event loop and fail with `ConnectError::NotQueued`.
+    pub fn connect_on(&self, signal: impl Into<Signal>, slot: Slot, target: &LoopHandle, connection_type: ConnectionType) -> Result<ConnectionId, ConnectError> {
+        self.push_connection(signal, slot, connection_type, Some(target.clone()), None)
+    }
+
+    /// Connects a slot that runs on the next emit of `signal` only and is then disconnected.
+    pub fn connect_once(&self, signal: impl Into<Signal>, slot: Slot) -> Result<ConnectionId, ConnectError> {
+        self.connect_n(signal, slot, 1)
+    }
+
+    /// Connects a slot that runs on the next `n` emits of `signal` and is then disconnected.
+    ///
+    /// Emits racing on several threads never run it more than `n` times in total.
+    /// `n == 0` fails with `ConnectError::ZeroShots` instead of connecting nothing.
+    pub fn connect_n(&self, signal: impl Into<Signal>, slot: Slot, n: usize) -> Result<ConnectionId, ConnectError> {
+        self.connect_n_with(signal, slot, n, ConnectionType::default())
+    }
+
+    /// `connect_n` with a connection type, like `connect_with`.
+    pub fn connect_n_with(&self, signal: impl Into<Signal>, slot: Slot, n: usize, connection_type: ConnectionType) -> Result<ConnectionId, ConnectError> {
+        self.push_connection(signal, slot, connection_type, None, Some(n))
+    }
+
+    /// `connect_n` for a slot bound to an event loop, like `connect_on`.
+    pub fn connect_n_on(&self, signal: impl Into<Signal>, slot: Slot, n: usize, target: &LoopHandle, connection_type: ConnectionType) -> Result<ConnectionId, ConnectError> {
+        self.push_connection(signal, slot, connection_type, Some(target.clone()), Some(n))
+    }
+
+    fn push_connection(&self, signal: impl Into<Signal>, slot: Slot, connection_type: ConnectionType, target: Option<LoopHandle>, shots: Option<usize>) -> Result<ConnectionId, ConnectError> {
+        let signal = signal.into();
+        if target.is_some() && !matches!(connection_type, ConnectionType::Queued | ConnectionType::BlockingQueued) {
+            return Err(ConnectError::NotQueued { connection_type });
+        }
+        if shots == Some(0) {
+            return Err(ConnectError::ZeroShots);
+        }
+        let mut connections = write(&self.connections);
+        if let Some(&declared) = read(&self.schemas).get(&signal) {
+            if slot.arg_kind() != declared {
+                return Err(ConnectError::ArgKind { signal, declared, slot: slot.arg_kind() });
+            }
+        }
+        let id = ConnectionId::next();
+        let connection = Connection {
+            id,
+            action: Action::Slot(slot),
+            connection_type,
+            target,
+            label: None,
+            shots: shots.map(|n| Arc::new(AtomicUsize::new(n))),
+            priority: 0,
+            enabled: true,
+        };
+        self.add_connection(connections.entry(signal).or_default(), connection);
+        Ok(id)
+    }
+
+    /// Re-emits every emit of `from` on `to` with the same arguments.
+    ///
+    /// The forward runs inline on the emitting thread; the slots of `to` are
+    /// dispatched by their own connection types and their results show up as
+    /// `SlotOutcome::Forwarded` in the report of `from`. Fails if `to` already
+    /// forwards back to `from`, directly or through other signals, or if both
+    /// are declared with different argument kinds.
+    pub fn connect_forward(&self, from: impl Into<Signal>, to: impl Into<Signal>) -> Result<ConnectionId, ConnectError> {
+        self.push_forward(from.into(), to.into(), None)
+    }
+
+    /// Like `connect_forward`, but passes the arguments through `map` first,
+    /// e.g. to turn an `Int` signal into a `String` one.
+    pub fn connect_forward_map<F>(&self, from: impl Into<Signal>, to: impl Into<Signal>, map: F) -> Result<ConnectionId, ConnectError>
+    where
+        F: Fn(SlotArgs) -> SlotArgs + Send + Sync + 'static,
+    {
+        self.push_forward(from.into(), to.into(), Some(Arc::new(map)))
+    }
+
+    fn push_forward(&self, from: Signal, to: Signal, map: Option<ArgMap>) -> Result<ConnectionId, ConnectError> {
+        let mut connections = write(&self.connections);
+        if forwards_to(&connections, to, from) {
+            return Err(ConnectError::Cycle { from, to });
+        }
+        if map.is_none() {
+            let schemas = read(&self.schemas);
+            if let (Some(&from_kind), Some(&to_kind)) = (schemas.get(&from), schemas.get(&to)) {
+                if from_kind != to_kind {
+                    return Err(ConnectError::ForwardKind { from, from_kind, to, to_kind });
                 }
-                (Slot::FnIntArrayMethod((obj_mtx, func_mtx)), IntArray(data)) => {
-                    let (func_mtx, obj_mtx, data) = clone_all_method_arg(func_mtx, obj_mtx, data);
-                    let handle = create_slot_method_arg(func_mtx, obj_mtx, data);
-                    handle_vector.push(handle);
+            }
+        }
+        let id = ConnectionId::next();
+        let action = Action::Forward { to, map };
+        let connection = Connection { id, action, connection_type: ConnectionType::Direct, target: None, label: None, shots: None, priority: 0, enabled: true };
+        self.add_connection(connections.entry(from).or_default(), connection);
+        Ok(id)
+    }
+
+    /// Connects and returns a guard that disconnects again on drop.
+    pub fn connect_scoped(&self, signal: impl Into<Signal>, slot: Slot) -> Result<ScopedConnection<'_>, ConnectError> {
+        self.connect(signal, slot).map(|id| ScopedConnection::new(self, id))
+    }
+
+    /// Like `connect_scoped`, for handlers shared through an `Arc`. The guard
+    /// doesn't borrow the handler, so it can be stored next to the `Arc`, e.g.
+    /// in a widget, or be captured by a slot of the same handler.
+    pub fn connect_scoped_arc(self: &Arc<Self>, signal: impl Into<Signal>, slot: Slot) -> Result<ScopedConnection<'static>, ConnectError> {
+        self.connect(signal, slot).map(|id| ScopedConnection::owned(self, id))
+    }
+
+    /// Removes a single connection. Returns `false` if it was already gone.
+    pub fn disconnect(&self, id: ConnectionId) -> bool {
+        self.remove_where(|connection| connection.id == id) != 0
+    }
+
+    /// Removes every slot connected to `signal` and returns how many were removed.
+    pub fn disconnect_signal(&self, signal: impl Into<Signal>) -> usize {
+        //the guard is gone before the slots are dropped, see remove_where
+        let removed = write(&self.connections).remove(&signal.into());
+        removed.map_or(0, |removed| removed.len())
+    }
+
+    /// Removes every method slot bound to `obj` and returns how many were removed.
+    pub fn disconnect_receiver<T: Any + Send + Sync>(&self, obj: &Arc<Mutex<T>>) -> usize {
+        self.remove_where(|connection| connection.slot().is_some_and(|slot| slot.targets(obj)))
+    }
+
+    pub fn disconnect_all(&self) {
+        let removed = std::mem::take(&mut *write(&self.connections));
+        drop(removed);
+    }
+
+    /// Removes method slots whose weak receiver has been dropped and returns how many were removed.
+    pub fn prune(&self) -> usize {
+        let removed = self.remove_where(Connection::is_dead);
+        self.pruned.fetch_add(removed, Ordering::Relaxed);
+        removed
+    }
+
+    pub fn set_mismatch_policy(&self, policy: MismatchPolicy) {
+        *write(&self.mismatch_policy) = policy;
+    }
+
+    pub fn mismatch_policy(&self) -> MismatchPolicy {
+        *read(&self.mismatch_policy)
+    }
+
+    /// Total number of connections pruned over the lifetime of this handler.
+    pub fn pruned_count(&self) -> usize {
+        self.pruned.load(Ordering::Relaxed)
+    }
+
+    /// Every signal that has a connection or a declaration, sorted by name.
+    pub fn signals(&self) -> Vec<Signal> {
+        let mut signals = read(&self.connections).keys().copied().collect::<Vec<Signal>>();
+        signals.extend(read(&self.schemas).keys().copied());
+        signals.sort();
+        signals.dedup();
+        signals
+    }
+
+    /// The connections of `signal`, in the order their slots are started.
+    pub fn receivers(&self, signal: impl Into<Signal>) -> Vec<ConnectionInfo> {
+        let signal = signal.into();
+        match self.snapshot(&signal) {
+            Some(connections) => connections.iter().map(|connection| connection.info(signal)).collect(),
+            None => vec![],
+        }
+    }
+
+    /// Every connection of every signal, sorted by signal, then in the order their slots are started.
+    pub fn connections(&self) -> Vec<ConnectionInfo> {
+        self.signals().into_iter().flat_map(|signal| self.receivers(signal)).collect()
+    }
+
+    pub fn connection_count(&self) -> usize {
+        read(&self.connections).values().map(|list| list.len()).sum()
+    }
+
+    pub fn is_connected(&self, signal: impl Into<Signal>) -> bool {
+        read(&self.connections).contains_key(&signal.into())
+    }
+
+    /// Names a connection for introspection and `to_dot`. Returns `false` if it doesn't exist.
+    pub fn set_label(&self, id: ConnectionId, label: impl Into<String>) -> bool {
+        let label = label.into();
+        self.update_connection(id, |connection| connection.label = Some(label))
+    }
+
+    /// Sets the priority of a connection, `0` by default. Slots of a signal
+    /// are started highest priority first, connections of equal priority in
+    /// the order they were made. Returns `false` if it doesn't exist.
+    pub fn set_priority(&self, id: ConnectionId, priority: i32) -> bool {
+        self.update_connection(id, |connection| connection.priority = priority)
+    }
+
+    /// Mutes a connection without disconnecting it, so it keeps its place in
+    /// the order of its signal. Disabled slots are reported as
+    /// `SlotOutcome::Disabled`. Returns `false` if the connection doesn't exist.
+    pub fn set_enabled(&self, id: ConnectionId, enabled: bool) -> bool {
+        self.update_connection(id, |connection| connection.enabled = enabled)
+    }
+
+    pub fn set_dispatch_mode(&self, mode: DispatchMode) {
+        *write(&self.dispatch_mode) = mode;
+    }
+
+    pub fn dispatch_mode(&self) -> DispatchMode {
+        *read(&self.dispatch_mode)
+    }
+
+    /// Blocks `signal`, emits of it then run no slot until it is unblocked.
+    /// Returns whether it was blocked already.
+    pub fn block_signal(&self, signal: impl Into<Signal>) -> bool {
+        !write(&self.blocking).signals.insert(signal.into())
+    }
+
+    /// Unblocks `signal` and replays its held emits, unless the whole handler
+    /// is still blocked. Returns whether it was blocked.
+    pub fn unblock_signal(&self, signal: impl Into<Signal>) -> bool {
+        let mut blocking = write(&self.blocking);
+        let was_blocked = blocking.signals.remove(&signal.into());
+        self.replay(blocking);
+        was_blocked
+    }
+
+    /// Blocks or unblocks every signal at once, on top of the signals blocked
+    /// one by one. Returns whether the handler was blocked before.
+    pub fn set_blocked(&self, blocked: bool) -> bool {
+        let mut blocking = write(&self.blocking);
+        let was_blocked = std::mem::replace(&mut blocking.all, blocked);
+        self.replay(blocking);
+        was_blocked
+    }
+
+    /// Whether emits of `signal` are currently blocked, by itself or by the whole handler.
+    pub fn is_blocked(&self, signal: impl Into<Signal>) -> bool {
+        read(&self.blocking).is_blocked(signal.into())
+    }
+
+    /// Switching back to `BlockPolicy::Drop` discards the emits held so far.
+    pub fn set_block_policy(&self, policy: BlockPolicy) {
+        let mut blocking = write(&self.blocking);
+        blocking.policy = policy;
+        if policy == BlockPolicy::Drop {
+            blocking.held.clear();
+        }
+    }
+
+    pub fn block_policy(&self) -> BlockPolicy {
+        read(&self.blocking).policy
+    }
+
+    /// Holds back an emit of a blocked signal. Returns `false` if the signal isn't blocked.
+    fn hold(&self, signal: Signal, slot_args: &SlotArgs) -> bool {
+        if !read(&self.blocking).is_blocked(signal) {
+            return false;
+        }
+        //checked again, it may have been unblocked in between
+        let mut blocking = write(&self.blocking);
+        if !blocking.is_blocked(signal) {
+            return false;
+        }
+        if blocking.policy == BlockPolicy::Replay {
+            blocking.held.push((signal, slot_args.clone()));
+        }
+        true
+    }
+
+    /// Emits the held back emits of signals that are no longer blocked.
+    fn replay(&self, mut blocking: RwLockWriteGuard<'_, Blocking>) {
+        let held = std::mem::take(&mut blocking.held);
+        let (ready, held): (Vec<(Signal, SlotArgs)>, _) = held.into_iter().partition(|(signal, _)| !blocking.is_blocked(*signal));
+        blocking.held = held;
+        drop(blocking);
+        for (signal, slot_args) in ready {
+            //checked when held, but slots connected since then can fail a strict emit
+            if let Err(e) = self.emit(signal, slot_args) {
+                eprintln!("WARNING: replayed emit failed: {}", e);
+            }
+        }
+    }
+
+    /// The connection graph in Graphviz DOT format, e.g. for `dot -Tsvg`.
+    pub fn to_dot(&self) -> String {
+        dot::render(&self.signals(), &self.connections())
+    }
+
+    /// The current connections of `signal`. Cheap, it only clones an `Arc`.
+    fn snapshot(&self, signal: &Signal) -> Option<Arc<Vec<Connection>>> {
+        read(&self.connections).get(signal).cloned()
+    }
+
+    fn update_connection<F: FnOnce(&mut Connection)>(&self, id: ConnectionId, change: F) -> bool {
+        let mut connections = write(&self.connections);
+        for list in connections.values_mut() {
+            if let Some(index) = list.iter().position(|connection| connection.id == id) {
+                let list = Arc::make_mut(list);
+                change(&mut list[index]);
+                list.sort_by_key(Connection::order);
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Adds `connection` to `list` in order. Only this list is pruned, so
+    /// connecting doesn't get slower with the number of other signals.
+    fn add_connection(&self, list: &mut Arc<Vec<Connection>>, connection: Connection) {
+        self.prune_list(list);
+        insert_ordered(Arc::make_mut(list), connection);
+    }
+
+    fn prune_signal(&self, signal: &Signal) {
+        let removed = self.remove_from(signal, Connection::is_dead);
+        self.pruned.fetch_add(removed, Ordering::Relaxed);
+    }
+
+    fn prune_list(&self, list: &mut Arc<Vec<Connection>>) {
+        //don't copy a list an emit may hold just to prune nothing
+        if list.iter().any(Connection::is_dead) {
+            let list = Arc::make_mut(list);
+            let before = list.len();
+            list.retain(|connection| !connection.is_dead());
+            self.pruned.fetch_add(before - list.len(), Ordering::Relaxed);
+        }
+    }
+
+    /// Like `remove_where`, but only looks at the connections of `signal`.
+    fn remove_from<F: Fn(&Connection) -> bool>(&self, signal: &Signal, remove: F) -> usize {
+        let mut removed = vec![];
+        let mut connections = write(&self.connections);
+        if let Some(list) = connections.get_mut(signal) {
+            if list.iter().any(&remove) {
+                let list = Arc::make_mut(list);
+                let (gone, kept) = std::mem::take(list).into_iter().partition::<Vec<Connection>, _>(&remove);
+                *list = kept;
+                removed = gone;
+            }
+            if list.is_empty() {
+                connections.remove(signal);
+            }
+        }
+        drop(connections);
+        removed.len()
+    }
+
+    fn remove_where<F: Fn(&Connection) -> bool>(&self, remove: F) -> usize {
+        //dropping a slot may drop its receiver, whose Drop may use this handler,
+        //so the removed connections outlive the guard
+        let mut removed = vec![];
+        let mut connections = write(&self.connections);
+        for list in connections.values_mut() {
+            //don't copy a list an emit may hold if nothing is removed from it
+            if list.iter().any(&remove) {
+                let list = Arc::make_mut(list);
+                let (gone, kept) = std::mem::take(list).into_iter().partition::<Vec<Connection>, _>(&remove);
+                *list = kept;
+                removed.extend(gone);
+            }
+        }
+        connections.retain(|_, list| !list.is_empty());
+        drop(connections);
+        removed.len()
+    }
+
+    /// Runs every slot connected to `signal` and waits for them to finish.
+    ///
+    /// If `signal` is blocked no slot runs and `EmitReport::is_blocked` is set.
+    pub fn emit(&self, signal: impl Into<Signal>, slot_args: SlotArgs) -> Result<EmitReport, EmitError> {
+        self.emit_async(signal, slot_args).map(EmitHandle::wait)
+    }
+
+    /// Runs the slots of `signal` like `emit` and folds the values returned by
+    /// slots made with `create::returning_slot`, e.g. with `combiner::AllTrue`
+    /// to let every plugin veto closing a window.
+    ///
+    /// Other slots, values of another kind than `R` and `Queued` slots, which
+    /// aren't waited for, are left out. Unlike `typed::Signal::emit_collect`
+    /// every slot runs before the combiner sees the first value.
+    pub fn emit_collect<R, C>(&self, signal: impl Into<Signal>, slot_args: SlotArgs, combiner: C) -> Result<C::Output, EmitError>
+    where
+        R: SlotValue,
+        C: Combiner<R>,
+    {
+        let report = self.emit(signal, slot_args)?;
+        let values = report.values().into_iter().filter_map(|value| R::from_args(value.clone()));
+        Ok(combiner.combine(values))
+    }
+
+    /// Starts every slot connected to `signal` and returns without waiting for them.
+    ///
+    /// `Direct` slots still run inline before this returns, and in
+    /// `DispatchMode::Sequential` every slot does.
+    pub fn emit_async(&self, signal: impl Into<Signal>, slot_args: SlotArgs) -> Result<EmitHandle, EmitError> {
+        let signal = signal.into();
+        let received = slot_args.kind();
+        if let Some(declared) = self.declared_kind(signal) {
+            if declared != received {
+                return Err(EmitError::ArgKind { signal, declared, received });
+            }
+        }
+        let snapshot = self.snapshot(&signal).unwrap_or_default();
+        let connections = snapshot.as_slice();
+        let mismatches = connections.iter().filter_map(|connection| connection.mismatch(received)).collect::<Vec<ArgMismatch>>();
+
+        match self.mismatch_policy() {
+            MismatchPolicy::Ignore => (),
+            MismatchPolicy::Lenient => {
+                for mismatch in &mismatches {
+                    eprintln!("WARNING: skipped on signal '{}': {}", signal, mismatch);
                 }
-                (Slot::FnFloatMethod((obj_mtx, func_mtx)), Float(data)) => {
-                    let (func_mtx, obj_mtx, data) = clone_all_method_arg(func_mtx, obj_mtx, data);
-                    let handle = create_slot_method_arg(func_mtx, obj_mtx, data);
-                    handle_vector.push(handle);
+            }
+            MismatchPolicy::Strict => {
+                if !mismatches.is_empty() {
+                    return Err(EmitError::ArgMismatch { signal, mismatches });
                 }
-                (Slot::FnFloatArrayMethod((obj_mtx, func_mtx)), FloatArray(data)) => {
-                    let (func_mtx, obj_mtx, data) = clone_all_method_arg(func_mtx, obj_mtx, data);
-                    let handle = create_slot_method_arg(func_mtx, obj_mtx, data);
-                    handle_vector.push(handle);
+            }
+        }
+        //a blocked emit fails the same way an unblocked one would before it is held
+        if self.hold(signal, &slot_args) {
+            return Ok(EmitHandle::blocked());
+        }
+
+        let mut pending_vector = vec![];
+        let mut receiver_dropped = false;
+        let mut spent = false;
+        //only sequential emits can be stopped, the slots of a parallel one are all started at once
+        let stopped = match self.dispatch_mode() {
+            DispatchMode::Sequential => Some(Arc::new(AtomicBool::new(false))),
+            DispatchMode::Parallel => None,
+        };
+        for connection in connections {
+            if stopped.as_ref().is_some_and(|stopped| stopped.load(Ordering::Acquire)) {
+                pending_vector.push((connection.id, Pending::Done(SlotOutcome::Stopped)));
+                continue;
+            }
+            if !connection.enabled {
+                pending_vector.push((connection.id, Pending::Done(SlotOutcome::Disabled)));
+                continue;
+            }
+            if let Some(mismatch) = connection.mismatch(received) {
+                let outcome = SlotOutcome::Skipped { expected: mismatch.expected, received: mismatch.received };
+                pending_vector.push((connection.id, Pending::Done(outcome)));
+                continue;
+            }
+            if !connection.take_shot() {
+                spent = true;
+                continue;
+            }
+            spent |= connection.is_spent();
+            let mut pending = match &connection.action {
+                Action::Forward { to, map } => self.forward(*to, map.as_ref(), slot_args.clone()),
+                Action::Slot(slot) => match create_job(slot.clone(), slot_args.clone()) {
+                    Some(job) => {
+                        //queued slots run after this loop moved on, stopping them would stop nothing
+                        let flag = stopped.clone().filter(|_| connection.connection_type != ConnectionType::Queued);
+                        let job = propagation::watch(flag, job);
+                        self.dispatch(connection.connection_type, connection.target.as_ref(), job)
+                    }
+                    None => {
+                        receiver_dropped = true;
+                        Pending::Done(SlotOutcome::ReceiverDropped)
+                    }
+                },
+            };
+            if stopped.is_some() {
+                pending.wait();
+            }
+            pending_vector.push((connection.id, pending));
+        }
+        if receiver_dropped {
+            self.prune_signal(&signal);
+        }
+        if spent {
+            self.remove_from(&signal, Connection::is_spent);
+        }
+        Ok(EmitHandle::new(pending_vector))
+    }
+
+    fn forward(&self, to: Signal, map: Option<&ArgMap>, slot_args: SlotArgs) -> Pending {
+        let slot_args = match map {
+            Some(map) => match panic::catch_unwind(AssertUnwindSafe(|| map(slot_args))) {
+                Ok(mapped) => mapped,
+                Err(panic) => return Pending::Done(SlotOutcome::Failed(SlotError::Panic(panic_message(panic)))),
+            },
+            None => slot_args,
+        };
+        match self.emit_async(to, slot_args) {
+            Ok(handle) => Pending::Nested(handle),
+            Err(e) => Pending::Done(SlotOutcome::Failed(SlotError::user(e))),
+        }
+    }
+
+    fn dispatch(&self, connection_type: ConnectionType, target: Option<&LoopHandle>, job: SlotJob) -> Pending {
+        match connection_type {
+            ConnectionType::Direct => Pending::Done(run_job(job)),
+            ConnectionType::Spawned => {
+                let (sender, receiver) = mpsc::channel();
+                let task: Task = Box::new(move || {
+                    let _ = sender.send(run_job(job));
+                });
+                let task = match &self.pool {
+                    //a worker waiting on its own pool could starve it, so nested emits get a new thread
+                    Some(pool) if !pool.is_worker() => pool.submit(task).err(),
+                    _ => Some(task),
+                };
+                if let Some(task) = task {
+                    std::thread::spawn(task);
                 }
-                (Slot::FnBoolMethod((obj_mtx, func_mtx)), Bool(data)) => {
-                    let (func_mtx, obj_mtx, data) = clone_all_method_arg(func_mtx, obj_mtx, data);
-                    let handle = create_slot_method_arg(func_mtx, obj_mtx, data);
-                    handle_vector.push(handle);
+                Pending::Reply(receiver)
+            }
+            ConnectionType::Queued => {
+                let task: Task = Box::new(move || {
+                    run_job(job);
+                });
+                match self.post(target, task) {
+                    Ok(()) => Pending::Done(SlotOutcome::Queued),
+                    Err(()) => Pending::Done(SlotOutcome::Failed(SlotError::LoopClosed)),
                 }
-                (Slot::FnBoolArrayMethod((obj_mtx, func_mtx)), BoolArray(data)) => {
-                    let (func_mtx, obj_mtx, data) = clone_all_method_arg(func_mtx, obj_mtx, data);
-                    let handle = create_slot_method_arg(func_mtx, obj_mtx, data);
-                    handle_vector.push(handle);
+            }
+            ConnectionType::BlockingQueued => {
+                let event_loop = match target {
+                    Some(target) => target,
+                    None => self.dispatcher().handle(),
+                };
+                //waiting on our own loop would never return
+                if event_loop.is_current() {
+                    return Pending::Done(run_job(job));
                 }
-                (Slot::FnStringMethod((obj_mtx, func_mtx)), String(data)) => {
-                    let (func_mtx, obj_mtx, data) = clone_all_method_arg(func_mtx, obj_mtx, data);
-                    let handle = create_slot_method_arg(func_mtx, obj_mtx, data);
-                    handle_vector.push(handle);
+                let (sender, receiver) = mpsc::channel();
+                let task: Task = Box::new(move || {
+                    let _ = sender.send(run_job(job));
+                });
+                match self.post(target, task) {
+                    Ok(()) => Pending::Reply(receiver),
+                    Err(()) => Pending::Done(SlotOutcome::Failed(SlotError::LoopClosed)),
                 }
-                (Slot::FnStringArrayMethod((obj_mtx, func_mtx)), StringArray(data)) => {
-                    let (func_mtx, obj_mtx, data) = clone_all_method_arg(func_mtx, obj_mtx, data);
-                    let handle = create_slot_method_arg(func_mtx, obj_mtx, data);
-                    handle_vector.push(handle);
+            }
+        }
+    }
+
+    /// Posts to the connection's loop, or the dispatcher. After `shutdown` the
+    /// dispatcher is gone and its tasks run inline instead.
+    fn post(&self, target: Option<&LoopHandle>, task: Task) -> Result<(), ()> {
+        match target {
+            Some(target) => target.post_task(task).map_err(|_| ()),
+            None => {
+                if let Err(task) = self.dispatcher().handle().post_task(task) {
+                    task();
                 }
-                _ => ()
+                Ok(())
             }
         }
-        for handle in handle_vector {
-            match handle.join() {
-                Ok(()) => (),
-                Err(e) => eprintln!("ERROR: Couldn't join on handle: {:?}", e),
+    }
+
+    fn dispatcher(&self) -> &Dispatcher {
+        self.dispatcher.get_or_init(Dispatcher::start)
+    }
+}
+
+//lock helpers ignoring poisoning, a panicking slot never holds the handler's locks
+fn read<T>(lock: &RwLock<T>) -> RwLockReadGuard<'_, T> {
+    match lock.read() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    }
+}
+
+fn write<T>(lock: &RwLock<T>) -> RwLockWriteGuard<'_, T> {
+    match lock.write() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    }
+}
+
+/// Whether emitting `from` reaches `to` through forwards, or `from` is `to`.
+fn forwards_to(connections: &HashMap<Signal, Arc<Vec<Connection>>>, from: Signal, to: Signal) -> bool {
+    let mut stack = vec![from];
+    let mut seen = HashSet::new();
+    while let Some(signal) = stack.pop() {
+        if signal == to {
+            return true;
+        }
+        if !seen.insert(signal) {
+            continue;
+        }
+        for connection in connections.get(&signal).into_iter().flat_map(|list| list.iter()) {
+            if let Action::Forward { to: next, .. } = &connection.action {
+                stack.push(*next);
             }
         }
     }
+    false
+}
+
+fn insert_ordered(list: &mut Vec<Connection>, connection: Connection) {
+    let index = list.partition_point(|other| other.order() <= connection.order());
+    list.insert(index, connection);
+}
+
+fn run_job(job: SlotJob) -> SlotOutcome {
+    match panic::catch_unwind(AssertUnwindSafe(job)) {
+        Ok(Ok(None)) => SlotOutcome::Success,
+        Ok(Ok(Some(value))) => SlotOutcome::Returned(value),
+        Ok(Err(e)) => SlotOutcome::Failed(e),
+        Err(panic) => SlotOutcome::Failed(SlotError::Panic(panic_message(panic))),
+    }
+}
+
+fn panic_message(panic: Box<dyn Any + Send>) -> String {
+    match panic.downcast::<String>() {
+        Ok(message) => *message,
+        Err(panic) => match panic.downcast::<&'static str>() {
+            Ok(message) => String::from(*message),
+            Err(_) => String::from("unknown panic payload"),
+        },
+    }
+}
+
+//builds the job running `slot`, `None` if its weak receiver is gone
+fn create_job(slot: Slot, slot_args: SlotArgs) -> Option<SlotJob> {
+    use self::Slot::*;
+    use self::SlotArgs::*;
+    match (slot, slot_args) {
+        (FnNone(func), None) => {
+            let func = clone_all_function_none(func);
+            Some(create_slot_function_none(func))
+        }
+        (FnInt(func), Int(data)) => {
+            let (func, data) = clone_all_function_arg(func, data);
+            Some(create_slot_function_arg(func, data))
+        }
+        (FnIntArray(func), IntArray(data)) => {
+            let (func, data) = clone_all_function_arg(func, data);
+            Some(create_slot_function_arg(func, data))
+        }
+        (FnFloat(func), Float(data)) => {
+            let (func, data) = clone_all_function_arg(func, data);
+            Some(create_slot_function_arg(func, data))
+        }
+        (FnFloatArray(func), FloatArray(data)) => {
+            let (func, data) = clone_all_function_arg(func, data);
+            Some(create_slot_function_arg(func, data))
+        }
+        (FnBool(func), Bool(data)) => {
+            let (func, data) = clone_all_function_arg(func, data);
+            Some(create_slot_function_arg(func, data))
+        }
+        (FnBoolArray(func), BoolArray(data)) => {
+            let (func, data) = clone_all_function_arg(func, data);
+            Some(create_slot_function_arg(func, data))
+        }
+        (FnString(func), String(data)) => {
+            let (func, data) = clone_all_function_arg(func, data);
+            Some(create_slot_function_arg(func, data))
+        }
+        (FnStringArray(func), StringArray(data)) => {
+            let (func, data) = clone_all_function_arg(func, data);
+            Some(create_slot_function_arg(func, data))
+        }
+        (FnNoneMethod((receiver, func)), None) => {
+            clone_all_method_none(func, &receiver)
+                .map(|(func, mtx)| create_slot_method_none(func, mtx))
+        }
+        (FnIntMethod((receiver, func)), Int(data)) => {
+            clone_all_method_arg(func, &receiver, data)
+                .map(|(func, obj_mtx, data)| create_slot_method_arg(func, obj_mtx, data))
+        }
+        (FnIntArrayMethod((receiver, func)), IntArray(data)) => {
+            clone_all_method_arg(func, &receiver, data)
+                .map(|(func, obj_mtx, data)| create_slot_method_arg(func, obj_mtx, data))
+        }
+        (FnFloatMethod((receiver, func)), Float(data)) => {
+            clone_all_method_arg(func, &receiver, data)
+                .map(|(func, obj_mtx, data)| create_slot_method_arg(func, obj_mtx, data))
+        }
+        (FnFloatArrayMethod((receiver, func)), FloatArray(data)) => {
+            clone_all_method_arg(func, &receiver, data)
+                .map(|(func, obj_mtx, data)| create_slot_method_arg(func, obj_mtx, data))
+        }
+        (FnBoolMethod((receiver, func)), Bool(data)) => {
+            clone_all_method_arg(func, &receiver, data)
+                .map(|(func, obj_mtx, data)| create_slot_method_arg(func, obj_mtx, data))
+        }
+        (FnBoolArrayMethod((receiver, func)), BoolArray(data)) => {
+            clone_all_method_arg(func, &receiver, data)
+                .map(|(func, obj_mtx, data)| create_slot_method_arg(func, obj_mtx, data))
+        }
+        (FnStringMethod((receiver, func)), String(data)) => {
+            clone_all_method_arg(func, &receiver, data)
+                .map(|(func, obj_mtx, data)| create_slot_method_arg(func, obj_mtx, data))
+        }
+        (FnStringArrayMethod((receiver, func)), StringArray(data)) => {
+            clone_all_method_arg(func, &receiver, data)
+                .map(|(func, obj_mtx, data)| create_slot_method_arg(func, obj_mtx, data))
+        }
+        (FnReturn((_, func)), data) => {
+            Some(Box::new(move || func(data).map(Some)))
+        }
+        (FnReturnMethod((receiver, _, func)), data) => {
+            receiver.upgrade().map(|obj_mtx| -> SlotJob {
+                Box::new(move || {
+                    let mut obj_guard = obj_mtx.lock().map_err(|_| SlotError::Poisoned)?;
+                    func(obj_guard.deref_mut(), data).map(Some)
+                })
+            })
+        }
+        _ => unreachable!("slot kinds are filtered before dispatch"),
+    }
 }
 
 //clone functions for slot receiving
 #[allow(clippy::type_complexity)]
-fn clone_all_function_none(func_mtx: Arc<Mutex<dyn Fn() -> Result<(), String> + Send + Sync>>)
-    -> Arc<Mutex<dyn Fn() -> Result<(), String> + Send + Sync>>{
-    Arc::clone(&func_mtx)
+fn clone_all_function_none(func: Arc<dyn Fn() -> Result<(), SlotError> + Send + Sync>)
+    -> Arc<dyn Fn() -> Result<(), SlotError> + Send + Sync>{
+    Arc::clone(&func)
 }
 
 #[allow(clippy::type_complexity)]
-fn clone_all_function_arg<D : Clone>(func_mtx: Arc<Mutex<dyn Fn(D) -> Result<(), String> + Send + Sync>>, data : D)
-    -> (Arc<Mutex<dyn Fn(D) -> Result<(), String> + Send + Sync>>,
+fn clone_all_function_arg<D : Clone>(func: Arc<dyn Fn(D) -> Result<(), SlotError> + Send + Sync>, data : D)
+    -> (Arc<dyn Fn(D) -> Result<(), SlotError> + Send + Sync>,
         D){
-    (Arc::clone(&func_mtx),data)
+    (Arc::clone(&func),data)
 }
 
 #[allow(clippy::type_complexity)]
-fn clone_all_method_none(func_mtx: Arc<Mutex<dyn Fn(&mut dyn Any) -> Result<(), String> + Send + Sync>>, obj_mtx : Arc<Mutex<dyn Any+Send+Sync>>)
-    -> (Arc<Mutex<dyn Fn(&mut dyn Any) -> Result<(), String> + Send + Sync>>,
-        Arc<Mutex<dyn Any+Send+Sync>>){
-    (Arc::clone(&func_mtx), Arc::clone(&obj_mtx))
+fn clone_all_method_none(func: Arc<dyn Fn(&mut dyn Any) -> Result<(), SlotError> + Send + Sync>, receiver : &Receiver)
+    -> Option<(Arc<dyn Fn(&mut dyn Any) -> Result<(), SlotError> + Send + Sync>,
+        Arc<Mutex<dyn Any+Send+Sync>>)>{
+    receiver.upgrade().map(|obj_mtx| (Arc::clone(&func), obj_mtx))
 }
 
 #[allow(clippy::type_complexity)]
-fn clone_all_method_arg<D : Clone>(func_mtx: Arc<Mutex<dyn Fn(&mut dyn Any, D) -> Result<(), String> + Send + Sync>>, obj_mtx : Arc<Mutex<dyn Any+Send+Sync>>, data : D)
-    -> (Arc<Mutex<dyn Fn(&mut dyn Any, D) -> Result<(), String> + Send + Sync>>,
+fn clone_all_method_arg<D : Clone>(func: Arc<dyn Fn(&mut dyn Any, D) -> Result<(), SlotError> + Send + Sync>, receiver : &Receiver, data : D)
+    -> Option<(Arc<dyn Fn(&mut dyn Any, D) -> Result<(), SlotError> + Send + Sync>,
         Arc<Mutex<dyn Any+Send+Sync>>,
-        D){
-    (Arc::clone(&func_mtx), Arc::clone(&obj_mtx), data)
+        D)>{
+    receiver.upgrade().map(|obj_mtx| (Arc::clone(&func), obj_mtx, data))
 }
 
-//functions for thread spawning
+//functions for job creation
 
 #[allow(clippy::type_complexity)]
-fn create_slot_function_none(func_mtx: Arc<Mutex<dyn Fn() -> Result<(), String> + Send + Sync>>) -> JoinHandle<()>{
-    std::thread::spawn(move || {
-        let func_guard = func_mtx.lock().unwrap();
-        let func = func_guard.deref();
-
-        match func() {
-            Ok(()) => (),
-            Err(e) => eprintln!("ERROR: {}", e),
-        }
+fn create_slot_function_none(func: Arc<dyn Fn() -> Result<(), SlotError> + Send + Sync>) -> SlotJob{
+    Box::new(move || {
+        func().map(|()| None)
     })
 }
 
 #[allow(clippy::type_complexity)]
-fn create_slot_function_arg<D: Send + 'static>(func_mtx: Arc<Mutex<dyn Fn(D) -> Result<(), String> + Send + Sync>>, data : D) -> JoinHandle<()>{
-    std::thread::spawn(move || {
-        let func_guard = func_mtx.lock().unwrap();
-        let func = func_guard.deref();
-
-        match func(data) {
-            Ok(()) => (),
-            Err(e) => eprintln!("ERROR: {}", e),
-        }
+fn create_slot_function_arg<D: Send + 'static>(func: Arc<dyn Fn(D) -> Result<(), SlotError> + Send + Sync>, data : D) -> SlotJob{
+    Box::new(move || {
+        func(data).map(|()| None)
     })
 }
 
 #[allow(clippy::type_complexity)]
-fn create_slot_method_none(func_mtx: Arc<Mutex<dyn Fn(&mut dyn Any) -> Result<(), String> + Send + Sync>>, obj_mtx : Arc<Mutex<dyn Any+Send+Sync>>) -> JoinHandle<()>{
-    std::thread::spawn(move || {
-        let func_guard = func_mtx.lock().unwrap();
-        let func = func_guard.deref();
-
-        let mut obj_guard = obj_mtx.lock().unwrap();
+fn create_slot_method_none(func: Arc<dyn Fn(&mut dyn Any) -> Result<(), SlotError> + Send + Sync>, obj_mtx : Arc<Mutex<dyn Any+Send+Sync>>) -> SlotJob{
+    Box::new(move || {
+        let mut obj_guard = obj_mtx.lock().map_err(|_| SlotError::Poisoned)?;
         let obj = obj_guard.deref_mut();
 
-        match func(obj) {
-            Ok(()) => (),
-            Err(e) => eprintln!("ERROR: {}", e),
-        }
+        func(obj).map(|()| None)
     })
 }
 
 #[allow(clippy::type_complexity)]
-fn create_slot_method_arg<D: Send + 'static>(func_mtx: Arc<Mutex<dyn Fn(&mut dyn Any, D) -> Result<(), String> + Send + Sync>>, obj_mtx : Arc<Mutex<dyn Any+Send+Sync>>, data : D) -> JoinHandle<()>{
-    std::thread::spawn(move || {
-        let func_guard = func_mtx.lock().unwrap();
-        let func = func_guard.deref();
-
-        let mut obj_guard = obj_mtx.lock().unwrap();
+fn create_slot_method_arg<D: Send + 'static>(func: Arc<dyn Fn(&mut dyn Any, D) -> Result<(), SlotError> + Send + Sync>, obj_mtx : Arc<Mutex<dyn Any+Send+Sync>>, data : D) -> SlotJob{
+    Box::new(move || {
+        let mut obj_guard = obj_mtx.lock().map_err(|_| SlotError::Poisoned)?;
         let obj = obj_guard.deref_mut();
 
-        match func(obj, data) {
-            Ok(()) => (),
-            Err(e) => eprintln!("ERROR: {}", e),
-        }
+        func(obj, data).map(|()| None)
     })
 }
@@ -0,0 +1,124 @@
+use std::sync::mpsc::{self, Receiver, Sender, SyncSender};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle, ThreadId};
+
+use super::dispatch::Task;
+
+/// Settings for the worker pool of a `SignalAndSlotHandler`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PoolConfig {
+    /// Number of worker threads.
+    pub size: usize,
+    /// Workers are named `<thread_name>-<index>`.
+    pub thread_name: String,
+    /// Stack size of each worker, `None` uses the platform default.
+    pub stack_size: Option<usize>,
+    /// Maximum number of slots waiting for a worker, `None` for no limit.
+    /// A full queue blocks the emitting thread until a worker is free.
+    pub queue_bound: Option<usize>,
+}
+
+impl Default for PoolConfig {
+    fn default() -> Self {
+        PoolConfig {
+            size: thread::available_parallelism().map(|n| n.get()).unwrap_or(4),
+            thread_name: String::from("sigs_slots-worker"),
+            stack_size: None,
+            queue_bound: None,
+        }
+    }
+}
+
+enum PoolSender {
+    Bounded(SyncSender<Task>),
+    Unbounded(Sender<Task>),
+}
+
+pub(crate) struct ThreadPool {
+    sender: Mutex<Option<PoolSender>>,
+    workers: Mutex<Vec<JoinHandle<()>>>,
+    worker_ids: Vec<ThreadId>,
+}
+
+impl ThreadPool {
+    pub(crate) fn new(config: PoolConfig) -> ThreadPool {
+        let (sender, receiver) = match config.queue_bound {
+            Some(bound) => {
+                let (sender, receiver) = mpsc::sync_channel(bound);
+                (PoolSender::Bounded(sender), receiver)
+            }
+            None => {
+                let (sender, receiver) = mpsc::channel();
+                (PoolSender::Unbounded(sender), receiver)
+            }
+        };
+        let receiver = Arc::new(Mutex::new(receiver));
+
+        let workers = (0..config.size.max(1))
+            .map(|index| {
+                let mut builder = thread::Builder::new().name(format!("{}-{}", config.thread_name, index));
+                if let Some(stack_size) = config.stack_size {
+                    builder = builder.stack_size(stack_size);
+                }
+                let receiver = Arc::clone(&receiver);
+                builder
+                    .spawn(move || work(receiver))
+                    .expect("couldn't spawn pool worker")
+            })
+            .collect::<Vec<JoinHandle<()>>>();
+
+        ThreadPool {
+            sender: Mutex::new(Some(sender)),
+            worker_ids: workers.iter().map(|worker| worker.thread().id()).collect(),
+            workers: Mutex::new(workers),
+        }
+    }
+
+    /// Queues `task` for a worker. Gives the task back if the pool is shut down.
+    pub(crate) fn submit(&self, task: Task) -> Result<(), Task> {
+        let sender = match self.sender.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        match sender.as_ref() {
+            Some(PoolSender::Bounded(sender)) => sender.send(task).map_err(|e| e.0),
+            Some(PoolSender::Unbounded(sender)) => sender.send(task).map_err(|e| e.0),
+            None => Err(task),
+        }
+    }
+
+    pub(crate) fn is_worker(&self) -> bool {
+        self.worker_ids.contains(&thread::current().id())
+    }
+
+    /// Stops accepting work and waits until every queued task has run.
+    pub(crate) fn shutdown(&self) {
+        match self.sender.lock() {
+            Ok(mut guard) => guard.take(),
+            Err(poisoned) => poisoned.into_inner().take(),
+        };
+        let workers = match self.workers.lock() {
+            Ok(mut guard) => std::mem::take(&mut *guard),
+            Err(poisoned) => std::mem::take(&mut *poisoned.into_inner()),
+        };
+        let current = thread::current().id();
+        for worker in workers {
+            if worker.thread().id() != current {
+                let _ = worker.join();
+            }
+        }
+    }
+}
+
+fn work(receiver: Arc<Mutex<Receiver<Task>>>) {
+    loop {
+        let task = match receiver.lock() {
+            Ok(guard) => guard.recv(),
+            Err(poisoned) => poisoned.into_inner().recv(),
+        };
+        match task {
+            Ok(task) => task(),
+            Err(_) => return,
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use std::cell::RefCell;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use super::SlotJob;
+
+thread_local! {
+    //stop flag of the emit whose slot is running on this thread
+    static CURRENT: RefCell<Option<Arc<AtomicBool>>> = const { RefCell::new(None) };
+}
+
+/// Called from a slot, keeps the emit running it from starting any further slots.
+///
+/// Only `DispatchMode::Sequential` emits can be stopped; the slots after the
+/// calling one are reported as `SlotOutcome::Stopped`. Returns `false` if the
+/// caller isn't a slot of such an emit.
+pub fn stop_propagation() -> bool {
+    CURRENT.with(|current| match &*current.borrow() {
+        Some(stopped) => {
+            stopped.store(true, Ordering::Release);
+            true
+        }
+        None => false,
+    })
+}
+
+/// Runs `job` with `stopped` as the flag `stop_propagation` sets.
+///
+/// Every slot job goes through this. Parallel emits and `Queued` slots pass
+/// `None`, so a slot never sees the flag of an emit that isn't waiting on it.
+pub(crate) fn watch(stopped: Option<Arc<AtomicBool>>, job: SlotJob) -> SlotJob {
+    Box::new(move || {
+        let previous = CURRENT.with(|current| current.replace(stopped));
+        let _restore = Restore(previous);
+        job()
+    })
+}
+
+//puts back the flag of an outer emit, also when the slot panics
+struct Restore(Option<Arc<AtomicBool>>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        let previous = self.0.take();
+        CURRENT.with(|current| *current.borrow_mut() = previous);
+    }
+}
@@ -0,0 +1,123 @@
+use super::{ArgKind, ConnectionId, SlotArgs, SlotError};
+
+/// What happened to a single connected slot during an emit.
+#[derive(Debug)]
+pub enum SlotOutcome {
+    Success,
+    /// A slot made with `create::returning_slot` ran and returned this.
+    Returned(SlotArgs),
+    /// The slot returned an error or panicked.
+    Failed(SlotError),
+    /// The slot expects a different `SlotArgs` variant than was emitted.
+    Skipped { expected: ArgKind, received: ArgKind },
+    /// The weak receiver of a method slot has been dropped.
+    ReceiverDropped,
+    /// The slot was handed to a dispatcher and runs later, its result is not tracked.
+    Queued,
+    /// The connection was disabled with `SignalAndSlotHandler::set_enabled`.
+    Disabled,
+    /// An earlier slot called `stop_propagation`, so this one wasn't started.
+    Stopped,
+    /// The connection forwarded the emit to another signal, with that emit's results.
+    Forwarded(EmitReport),
+}
+
+impl SlotOutcome {
+    pub fn is_failure(&self) -> bool {
+        match self {
+            SlotOutcome::Failed(_) => true,
+            SlotOutcome::Forwarded(report) => !report.is_ok(),
+            _ => false,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct SlotResult {
+    pub connection: ConnectionId,
+    pub outcome: SlotOutcome,
+}
+
+/// Per-slot results of one emit, in the order the slots were started.
+#[derive(Debug, Default)]
+pub struct EmitReport {
+    results: Vec<SlotResult>,
+    blocked: bool,
+}
+
+impl EmitReport {
+    pub(crate) fn blocked() -> EmitReport {
+        EmitReport { results: vec![], blocked: true }
+    }
+
+    pub fn results(&self) -> &[SlotResult] {
+        &self.results
+    }
+
+    pub fn outcome(&self, connection: ConnectionId) -> Option<&SlotOutcome> {
+        self.results
+            .iter()
+            .find(|result| result.connection == connection)
+            .map(|result| &result.outcome)
+    }
+
+    /// Results of slots that returned an error or panicked, including forwards
+    /// where a slot of the forwarded signal failed.
+    pub fn failures(&self) -> impl Iterator<Item = &SlotResult> {
+        self.results.iter().filter(|result| result.outcome.is_failure())
+    }
+
+    /// `true` if no slot returned an error or panicked.
+    pub fn is_ok(&self) -> bool {
+        self.failures().next().is_none()
+    }
+
+    /// Number of slots that actually ran, counting those reached through forwards.
+    pub fn invoked(&self) -> usize {
+        self.results
+            .iter()
+            .map(|result| match &result.outcome {
+                SlotOutcome::Success | SlotOutcome::Returned(_) | SlotOutcome::Failed(_) => 1,
+                SlotOutcome::Forwarded(report) => report.invoked(),
+                _ => 0,
+            })
+            .sum()
+    }
+
+    /// `true` if a slot called `stop_propagation` and later slots were left out.
+    pub fn is_stopped(&self) -> bool {
+        self.results.iter().any(|result| matches!(result.outcome, SlotOutcome::Stopped))
+    }
+
+    /// The values returned by slots, in the order the slots were started,
+    /// including those of slots reached through forwards.
+    pub fn values(&self) -> Vec<&SlotArgs> {
+        let mut values = vec![];
+        for result in &self.results {
+            match &result.outcome {
+                SlotOutcome::Returned(value) => values.push(value),
+                SlotOutcome::Forwarded(report) => values.extend(report.values()),
+                _ => (),
+            }
+        }
+        values
+    }
+
+    /// `true` if the signal was blocked, so no slot was even looked at.
+    /// An emit of a signal without connections has an empty report too, but isn't blocked.
+    pub fn is_blocked(&self) -> bool {
+        self.blocked
+    }
+
+    pub fn len(&self) -> usize {
+        self.results.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.results.is_empty()
+    }
+
+    pub(crate) fn push(&mut self, connection: ConnectionId, outcome: SlotOutcome) {
+        self.results.push(SlotResult { connection, outcome });
+    }
+}
@@ -0,0 +1,62 @@
+use std::sync::{Arc, Weak};
+
+use super::{ConnectionId, SignalAndSlotHandler};
+
+/// Guard that disconnects its connection from the handler when dropped.
+///
+/// Made by `connect_scoped`, which borrows the handler, or `connect_scoped_arc`,
+/// which only keeps a weak reference to it and can be stored anywhere.
+pub struct ScopedConnection<'a> {
+    handler: HandlerRef<'a>,
+    id: Option<ConnectionId>,
+}
+
+enum HandlerRef<'a> {
+    Borrowed(&'a SignalAndSlotHandler),
+    //doesn't keep the handler alive, there is nothing to disconnect once it is gone
+    Weak(Weak<SignalAndSlotHandler>),
+}
+
+impl<'a> ScopedConnection<'a> {
+    pub(crate) fn new(handler: &'a SignalAndSlotHandler, id: ConnectionId) -> ScopedConnection<'a> {
+        ScopedConnection {
+            handler: HandlerRef::Borrowed(handler),
+            id: Some(id),
+        }
+    }
+
+    pub fn id(&self) -> ConnectionId {
+        self.id.expect("ScopedConnection without id")
+    }
+
+    /// Gives up the guard and keeps the connection alive.
+    pub fn release(mut self) -> ConnectionId {
+        self.id.take().expect("ScopedConnection without id")
+    }
+}
+
+impl ScopedConnection<'static> {
+    pub(crate) fn owned(handler: &Arc<SignalAndSlotHandler>, id: ConnectionId) -> ScopedConnection<'static> {
+        ScopedConnection {
+            handler: HandlerRef::Weak(Arc::downgrade(handler)),
+            id: Some(id),
+        }
+    }
+}
+
+impl<'a> Drop for ScopedConnection<'a> {
+    fn drop(&mut self) {
+        if let Some(id) = self.id.take() {
+            match &self.handler {
+                HandlerRef::Borrowed(handler) => {
+                    handler.disconnect(id);
+                }
+                HandlerRef::Weak(handler) => {
+                    if let Some(handler) = handler.upgrade() {
+                        handler.disconnect(id);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use std::collections::HashSet;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::Mutex;
+
+use lazy_static::lazy_static;
+
+lazy_static! {
+    /// Names of signals created from runtime strings, leaked once per distinct name.
+    static ref INTERNED: Mutex<HashSet<&'static str>> = Mutex::new(HashSet::new());
+}
+
+/// Name of a signal, cheap to copy, hash and compare.
+///
+/// Declare signals once with `signal!` and reuse them:
+///
+/// ```
+/// use sigs_slots::{signal, SignalId};
+///
+/// static CLICKED: SignalId = signal!("ui.clicked");
+/// assert_eq!(CLICKED, SignalId::from("ui.clicked"));
+/// ```
+///
+/// Converting from `&str` or `String` interns the name, which takes a global
+/// lock, so hot paths should use declared ids.
+#[derive(Clone, Copy)]
+pub struct SignalId {
+    name: &'static str,
+    hash: u64,
+}
+
+impl SignalId {
+    pub const fn new(name: &'static str) -> SignalId {
+        SignalId {
+            name,
+            hash: fnv1a(name.as_bytes()),
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn intern(name: &str) -> SignalId {
+        let mut interned = match INTERNED.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        let name = match interned.get(name) {
+            Some(name) => *name,
+            None => {
+                let name: &'static str = Box::leak(String::from(name).into_boxed_str());
+                interned.insert(name);
+                name
+            }
+        };
+        SignalId::new(name)
+    }
+}
+
+/// Declares a `SignalId` in a `static` or `const`.
+#[macro_export]
+macro_rules! signal {
+    ($name:expr) => {
+        $crate::SignalId::new($name)
+    };
+}
+
+const fn fnv1a(bytes: &[u8]) -> u64 {
+    let mut hash = 0xcbf2_9ce4_8422_2325u64;
+    let mut i = 0;
+    while i < bytes.len() {
+        hash ^= bytes[i] as u64;
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+        i += 1;
+    }
+    hash
+}
+
+impl PartialEq for SignalId {
+    fn eq(&self, other: &SignalId) -> bool {
+        self.hash == other.hash && (std::ptr::eq(self.name, other.name) || self.name == other.name)
+    }
+}
+
+impl Eq for SignalId {}
+
+impl Hash for SignalId {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u64(self.hash);
+    }
+}
+
+impl PartialOrd for SignalId {
+    fn partial_cmp(&self, other: &SignalId) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SignalId {
+    fn cmp(&self, other: &SignalId) -> std::cmp::Ordering {
+        self.name.cmp(other.name)
+    }
+}
+
+impl fmt::Debug for SignalId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SignalId({:?})", self.name)
+    }
+}
+
+impl fmt::Display for SignalId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name)
+    }
+}
+
+impl From<&SignalId> for SignalId {
+    fn from(signal: &SignalId) -> Self {
+        *signal
+    }
+}
+
+impl From<&str> for SignalId {
+    fn from(name: &str) -> Self {
+        SignalId::intern(name)
+    }
+}
+
+impl From<String> for SignalId {
+    fn from(name: String) -> Self {
+        SignalId::intern(&name)
+    }
+}
+
+impl From<&String> for SignalId {
+    fn from(name: &String) -> Self {
+        SignalId::intern(name)
+    }
+}
@@ -0,0 +1,90 @@
+use std::sync::{Arc, Mutex};
+
+use super::combiner::Combiner;
+use super::ConnectionId;
+
+type TypedSlot<A, R> = Arc<dyn Fn(A) -> R + Send + Sync>;
+
+/// A signal whose argument type is checked at compile time.
+///
+/// Slots run on the emitting thread, in connection order. Unlike
+/// `SignalAndSlotHandler` no `SlotArgs` matching happens at runtime.
+/// Slots may return an `R`, which `emit_collect` hands to a `Combiner`.
+pub struct Signal<A, R = ()> {
+    slots: Mutex<Vec<(ConnectionId, TypedSlot<A, R>)>>,
+}
+
+impl<A, R> Default for Signal<A, R> {
+    fn default() -> Self {
+        Signal {
+            slots: Mutex::new(vec![]),
+        }
+    }
+}
+
+impl<A: Clone + Send + 'static, R: 'static> Signal<A, R> {
+    pub fn new() -> Signal<A, R> {
+        Signal::default()
+    }
+
+    pub fn connect<F: Fn(A) -> R + Send + Sync + 'static>(&self, slot: F) -> ConnectionId {
+        let id = ConnectionId::next();
+        self.lock().push((id, Arc::new(slot)));
+        id
+    }
+
+    /// Removes a single connection. Returns `false` if it was already gone.
+    pub fn disconnect(&self, id: ConnectionId) -> bool {
+        let mut slots = self.lock();
+        let before = slots.len();
+        slots.retain(|(slot_id, _)| *slot_id != id);
+        slots.len() != before
+    }
+
+    pub fn disconnect_all(&self) {
+        self.lock().clear();
+    }
+
+    pub fn connection_count(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Calls every connected slot with a clone of `args`, discarding what they return.
+    ///
+    /// The slot list is copied before calling, so slots may connect to or
+    /// disconnect from this signal without deadlocking.
+    pub fn emit(&self, args: A) {
+        let slots = self.snapshot();
+
+        if let Some((last, rest)) = slots.split_last() {
+            for slot in rest {
+                slot(args.clone());
+            }
+            last(args);
+        }
+    }
+
+    /// Calls the connected slots and combines their return values, e.g.
+    /// `emit_collect(args, combiner::AllTrue)` to let every slot veto.
+    ///
+    /// Slots are only called as the combiner asks for their values, so
+    /// combiners like `First` or `AllTrue` may leave later slots out.
+    pub fn emit_collect<C: Combiner<R>>(&self, args: A, combiner: C) -> C::Output {
+        let slots = self.snapshot();
+        combiner.combine(slots.iter().map(|slot| slot(args.clone())))
+    }
+
+    fn snapshot(&self) -> Vec<TypedSlot<A, R>> {
+        self.lock()
+            .iter()
+            .map(|(_, slot)| Arc::clone(slot))
+            .collect()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<(ConnectionId, TypedSlot<A, R>)>> {
+        match self.slots.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use std::sync::{Arc, Mutex};
+use sigs_slots::*;
+
+fn recorder(handler: &SignalAndSlotHandler, signal: &str) -> Arc<Mutex<Vec<i32>>> {
+    let seen = Arc::new(Mutex::new(vec![]));
+    let slot_seen = Arc::clone(&seen);
+    handler.connect_with(signal, create::int_slot(move |num| -> Result<(), SlotError> {
+        slot_seen.lock().unwrap().push(num);
+        Ok(())
+    }), ConnectionType::Direct).unwrap();
+    seen
+}
+
+#[test]
+fn signal_blocker_replays_held_emits_in_order() {
+    let handler = SignalAndSlotHandler::new();
+    handler.set_block_policy(BlockPolicy::Replay);
+    let seen = recorder(&handler, "value");
+
+    {
+        let _blocker = SignalBlocker::new(&handler, "value");
+        for num in 0..5 {
+            let report = handler.emit("value", SlotArgs::Int(num)).unwrap();
+            assert!(report.is_blocked());
+        }
+        assert!(seen.lock().unwrap().is_empty());
+    }
+    assert_eq!(*seen.lock().unwrap(), vec![0, 1, 2, 3, 4]);
+    assert!(!handler.is_blocked("value"));
+}
+
+#[test]
+fn nested_blockers_restore_previous_state() {
+    let handler = SignalAndSlotHandler::new();
+    handler.set_block_policy(BlockPolicy::Replay);
+    let seen = recorder(&handler, "value");
+
+    let outer = SignalBlocker::all(&handler);
+    {
+        let _inner = SignalBlocker::new(&handler, "value");
+        handler.emit("value", SlotArgs::Int(1)).unwrap();
+    }
+    //still held, the whole handler is blocked
+    assert!(handler.is_blocked("value"));
+    assert!(seen.lock().unwrap().is_empty());
+    drop(outer);
+    assert_eq!(*seen.lock().unwrap(), vec![1]);
+}
+
+#[test]
+fn dropped_emits_are_not_replayed() {
+    let handler = SignalAndSlotHandler::new();
+    let seen = recorder(&handler, "value");
+
+    handler.block_signal("value");
+    handler.emit("value", SlotArgs::Int(1)).unwrap();
+    handler.unblock_signal("value");
+    handler.emit("value", SlotArgs::Int(2)).unwrap();
+    assert_eq!(*seen.lock().unwrap(), vec![2]);
+}
+
+#[test]
+fn blocked_report_differs_from_unconnected() {
+    let handler = SignalAndSlotHandler::new();
+    let report = handler.emit("nothing", SlotArgs::None).unwrap();
+    assert!(report.is_empty());
+    assert!(!report.is_blocked());
+
+    handler.set_blocked(true);
+    let report = handler.emit("nothing", SlotArgs::None).unwrap();
+    assert!(report.is_empty());
+    assert!(report.is_blocked());
+}
+
+#[test]
+fn strict_mismatch_fails_blocked_emit_without_holding_it() {
+    let handler = SignalAndSlotHandler::new();
+    handler.set_mismatch_policy(MismatchPolicy::Strict);
+    handler.set_block_policy(BlockPolicy::Replay);
+    let seen = recorder(&handler, "value");
+
+    handler.block_signal("value");
+    let result = handler.emit("value", SlotArgs::None);
+    assert!(matches!(result, Err(EmitError::ArgMismatch { .. })));
+    handler.emit("value", SlotArgs::Int(3)).unwrap();
+    handler.unblock_signal("value");
+    assert_eq!(*seen.lock().unwrap(), vec![3]);
+}
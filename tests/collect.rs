@@ -0,0 +1,70 @@
+use std::sync::{Arc, Mutex};
+use sigs_slots::combiner::{AllTrue, AnyTrue, Collect, First, Last, Sum};
+use sigs_slots::*;
+
+struct Plugin {
+    dirty: bool,
+}
+
+fn vote(handler: &SignalAndSlotHandler, value: bool) {
+    handler.connect("can_close", create::returning_slot(move |()| -> Result<bool, SlotError> { Ok(value) })).unwrap();
+}
+
+#[test]
+fn boolean_combiners_vote() {
+    let handler = SignalAndSlotHandler::new();
+    assert!(handler.emit_collect("can_close", SlotArgs::None, AllTrue).unwrap());
+    assert!(!handler.emit_collect("can_close", SlotArgs::None, AnyTrue).unwrap());
+
+    vote(&handler, true);
+    let plugin = Arc::new(Mutex::new(Plugin { dirty: true }));
+    handler.connect("can_close", create::returning_method_slot(|plugin: &mut Plugin, ()| -> Result<bool, SlotError> {
+        Ok(!plugin.dirty)
+    }, Arc::clone(&plugin))).unwrap();
+
+    assert!(!handler.emit_collect("can_close", SlotArgs::None, AllTrue).unwrap());
+    assert!(handler.emit_collect("can_close", SlotArgs::None, AnyTrue).unwrap());
+    plugin.lock().unwrap().dirty = false;
+    assert!(handler.emit_collect("can_close", SlotArgs::None, AllTrue).unwrap());
+}
+
+#[test]
+fn values_are_combined_in_invocation_order() {
+    let handler = SignalAndSlotHandler::new();
+    handler.set_dispatch_mode(DispatchMode::Sequential);
+    let double = handler.connect("size", create::returning_slot(|n: i32| -> Result<i32, SlotError> { Ok(n * 2) })).unwrap();
+    handler.connect("size", create::returning_slot(|n: i32| -> Result<i32, SlotError> { Ok(n + 1) })).unwrap();
+    //slots without a value and values of another kind are left out
+    handler.connect("size", create::int_slot(|_| -> Result<(), SlotError> { Ok(()) })).unwrap();
+    handler.connect("size", create::returning_slot(|n: i32| -> Result<String, SlotError> { Ok(n.to_string()) })).unwrap();
+    handler.set_priority(double, -1);
+
+    let values: Vec<i32> = handler.emit_collect("size", SlotArgs::Int(5), Collect).unwrap();
+    assert_eq!(values, vec![6, 10]);
+    let sum: i32 = handler.emit_collect("size", SlotArgs::Int(5), Sum).unwrap();
+    assert_eq!(sum, 16);
+    assert_eq!(handler.emit_collect::<i32, _>("size", SlotArgs::Int(5), First).unwrap(), Some(6));
+    assert_eq!(handler.emit_collect::<i32, _>("size", SlotArgs::Int(5), Last).unwrap(), Some(10));
+    assert_eq!(handler.emit_collect::<String, _>("size", SlotArgs::Int(5), Collect).unwrap(), vec![String::from("5")]);
+}
+
+#[test]
+fn report_holds_returned_values() {
+    let handler = SignalAndSlotHandler::new();
+    let id = handler.connect("echo", create::returning_slot(|text: String| -> Result<String, SlotError> { Ok(text) })).unwrap();
+    handler.connect_forward("say", "echo").unwrap();
+
+    let report = handler.emit("say", SlotArgs::String(String::from("hi"))).unwrap();
+    assert_eq!(report.values(), vec![&SlotArgs::String(String::from("hi"))]);
+    assert_eq!(report.invoked(), 1);
+    let report = handler.emit("echo", SlotArgs::String(String::from("ho"))).unwrap();
+    assert!(matches!(report.outcome(id), Some(SlotOutcome::Returned(SlotArgs::String(text))) if text == "ho"));
+}
+
+#[test]
+fn emit_collect_reports_emit_errors() {
+    let handler = SignalAndSlotHandler::new();
+    handler.declare("can_close", ArgKind::None).unwrap();
+    let result = handler.emit_collect("can_close", SlotArgs::Int(1), AllTrue);
+    assert!(matches!(result, Err(EmitError::ArgKind { .. })));
+}
@@ -0,0 +1,12 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use sigs_slots::*;
+
+//a slot that counts how often it ran
+pub fn counting_slot(count: &Arc<AtomicUsize>) -> Slot {
+    let count = Arc::clone(count);
+    create::none_slot(move || -> Result<(), SlotError> {
+        count.fetch_add(1, Ordering::SeqCst);
+        Ok(())
+    })
+}
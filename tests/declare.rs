@@ -0,0 +1,69 @@
+use sigs_slots::*;
+
+fn noop() -> Result<(), SlotError> {
+    Ok(())
+}
+
+fn take_int(_: i32) -> Result<(), SlotError> {
+    Ok(())
+}
+
+#[test]
+fn redeclaring_with_another_kind_fails() {
+    let handler = SignalAndSlotHandler::new();
+    handler.declare("value", ArgKind::Int).unwrap();
+    handler.declare("value", ArgKind::Int).unwrap();
+    assert_eq!(handler.declare("value", ArgKind::String), Err(ConnectError::Redeclared {
+        signal: Signal::from("value"),
+        declared: ArgKind::Int,
+        requested: ArgKind::String,
+    }));
+    assert_eq!(handler.declared_kind("value"), Some(ArgKind::Int));
+}
+
+#[test]
+fn declaring_over_incompatible_slot_fails() {
+    let handler = SignalAndSlotHandler::new();
+    handler.connect("value", create::none_slot(noop)).unwrap();
+    assert_eq!(handler.declare("value", ArgKind::Int), Err(ConnectError::ArgKind {
+        signal: Signal::from("value"),
+        declared: ArgKind::Int,
+        slot: ArgKind::None,
+    }));
+    assert_eq!(handler.declared_kind("value"), None);
+}
+
+#[test]
+fn declaring_over_matching_slot_succeeds() {
+    let handler = SignalAndSlotHandler::new();
+    handler.connect("value", create::int_slot(take_int)).unwrap();
+    handler.declare("value", ArgKind::Int).unwrap();
+    assert_eq!(handler.declared_kind("value"), Some(ArgKind::Int));
+}
+
+#[test]
+fn connecting_slot_of_another_kind_fails() {
+    let handler = SignalAndSlotHandler::new();
+    handler.declare("value", ArgKind::Int).unwrap();
+    assert_eq!(handler.connect("value", create::none_slot(noop)), Err(ConnectError::ArgKind {
+        signal: Signal::from("value"),
+        declared: ArgKind::Int,
+        slot: ArgKind::None,
+    }));
+    assert_eq!(handler.connection_count(), 0);
+    handler.connect("value", create::int_slot(take_int)).unwrap();
+}
+
+#[test]
+fn emitting_another_kind_fails() {
+    let handler = SignalAndSlotHandler::new();
+    handler.declare("value", ArgKind::Int).unwrap();
+    handler.connect("value", create::int_slot(take_int)).unwrap();
+    let result = handler.emit("value", SlotArgs::Bool(true));
+    assert_eq!(result.err(), Some(EmitError::ArgKind {
+        signal: Signal::from("value"),
+        declared: ArgKind::Int,
+        received: ArgKind::Bool,
+    }));
+    assert_eq!(handler.emit("value", SlotArgs::Int(1)).unwrap().invoked(), 1);
+}
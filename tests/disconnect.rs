@@ -0,0 +1,82 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex, Weak};
+use std::thread;
+use std::time::Duration;
+use sigs_slots::*;
+
+//emits through the handler when the last slot holding it is dropped
+struct Window {
+    handler: Weak<SignalAndSlotHandler>,
+}
+
+impl Window {
+    fn show(&mut self) -> Result<(), SlotError> {
+        Ok(())
+    }
+}
+
+impl Drop for Window {
+    fn drop(&mut self) {
+        if let Some(handler) = self.handler.upgrade() {
+            handler.emit("closed", SlotArgs::None).unwrap();
+        }
+    }
+}
+
+fn connect_window(handler: &Arc<SignalAndSlotHandler>) -> ConnectionId {
+    let window = Arc::new(Mutex::new(Window { handler: Arc::downgrade(handler) }));
+    handler.connect("show", create::none_method_slot(Window::show, window)).unwrap()
+}
+
+//runs `disconnect` on another thread so a deadlock fails the test instead of hanging it
+fn disconnect_closes_window(disconnect: fn(&SignalAndSlotHandler, ConnectionId)) {
+    let handler = Arc::new(SignalAndSlotHandler::new());
+    let closed = Arc::new(AtomicUsize::new(0));
+    let slot_closed = Arc::clone(&closed);
+    handler.connect("closed", create::none_slot(move || -> Result<(), SlotError> {
+        slot_closed.fetch_add(1, Ordering::SeqCst);
+        Ok(())
+    })).unwrap();
+    let id = connect_window(&handler);
+
+    let (done_sender, done) = mpsc::channel();
+    let disconnector = {
+        let handler = Arc::clone(&handler);
+        thread::spawn(move || {
+            disconnect(&handler, id);
+            done_sender.send(()).unwrap();
+        })
+    };
+    done.recv_timeout(Duration::from_secs(5)).expect("disconnecting deadlocked");
+    disconnector.join().unwrap();
+    assert_eq!(closed.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn receiver_drop_can_emit_during_disconnect() {
+    disconnect_closes_window(|handler, id| assert!(handler.disconnect(id)));
+}
+
+#[test]
+fn receiver_drop_can_emit_during_disconnect_signal() {
+    disconnect_closes_window(|handler, _| assert_eq!(handler.disconnect_signal("show"), 1));
+}
+
+#[test]
+fn receiver_drop_can_emit_during_disconnect_all() {
+    //nothing is left to count the close, the emit just must not deadlock
+    let handler = Arc::new(SignalAndSlotHandler::new());
+    connect_window(&handler);
+    let (done_sender, done) = mpsc::channel();
+    let disconnector = {
+        let handler = Arc::clone(&handler);
+        thread::spawn(move || {
+            handler.disconnect_all();
+            done_sender.send(()).unwrap();
+        })
+    };
+    done.recv_timeout(Duration::from_secs(5)).expect("disconnecting deadlocked");
+    disconnector.join().unwrap();
+    assert_eq!(handler.connection_count(), 0);
+}
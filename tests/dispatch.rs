@@ -0,0 +1,25 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+use sigs_slots::*;
+
+#[test]
+fn dropping_the_handler_joins_its_dispatcher() {
+    let handler = SignalAndSlotHandler::new();
+    let done = Arc::new(AtomicBool::new(false));
+    let flag = Arc::clone(&done);
+    handler.connect_with("queued", create::none_slot(move || -> Result<(), SlotError> {
+        thread::sleep(Duration::from_millis(50));
+        flag.store(true, Ordering::SeqCst);
+        Ok(())
+    }), ConnectionType::Queued).unwrap();
+
+    let report = handler.emit("queued", SlotArgs::None).unwrap();
+    assert!(matches!(report.results()[0].outcome, SlotOutcome::Queued));
+    assert!(!done.load(Ordering::SeqCst));
+
+    //the dispatcher thread finishes the queued slot and exits before drop returns
+    drop(handler);
+    assert!(done.load(Ordering::SeqCst));
+}
@@ -0,0 +1,66 @@
+use std::sync::{Arc, Mutex};
+use std::thread;
+use sigs_slots::*;
+
+fn noop() -> Result<(), SlotError> {
+    Ok(())
+}
+
+#[test]
+fn connect_on_rejects_types_without_event_loop() {
+    let handler = SignalAndSlotHandler::new();
+    let event_loop = EventLoop::new();
+    for connection_type in [ConnectionType::Direct, ConnectionType::Spawned] {
+        let result = handler.connect_on("frame", create::none_slot(noop), &event_loop.handle(), connection_type);
+        assert_eq!(result, Err(ConnectError::NotQueued { connection_type }));
+    }
+    assert_eq!(handler.connection_count(), 0);
+}
+
+#[test]
+fn queued_slot_runs_on_the_thread_processing_the_loop() {
+    let handler = SignalAndSlotHandler::new();
+    let event_loop = EventLoop::new();
+    let ran_on = Arc::new(Mutex::new(None));
+    let slot_ran_on = Arc::clone(&ran_on);
+    handler.connect_on("frame", create::none_slot(move || -> Result<(), SlotError> {
+        *slot_ran_on.lock().unwrap() = Some(thread::current().id());
+        Ok(())
+    }), &event_loop.handle(), ConnectionType::Queued).unwrap();
+
+    let emitter = thread::spawn({
+        let handler = Arc::new(handler);
+        move || handler.emit("frame", SlotArgs::None).unwrap().len()
+    });
+    assert_eq!(emitter.join().unwrap(), 1);
+    assert!(ran_on.lock().unwrap().is_none());
+
+    assert_eq!(event_loop.process_events(), 1);
+    assert_eq!(*ran_on.lock().unwrap(), Some(thread::current().id()));
+}
+
+#[test]
+fn loop_created_here_but_run_elsewhere_is_not_current_here() {
+    let handler = SignalAndSlotHandler::new();
+    let event_loop = EventLoop::new();
+    let handle = event_loop.handle();
+    assert!(!handle.is_current());
+
+    let ran_on = Arc::new(Mutex::new(None));
+    let slot_ran_on = Arc::clone(&ran_on);
+    handler.connect_on("frame", create::none_slot(move || -> Result<(), SlotError> {
+        *slot_ran_on.lock().unwrap() = Some(thread::current().id());
+        Ok(())
+    }), &handle, ConnectionType::BlockingQueued).unwrap();
+
+    let runner = thread::spawn(move || {
+        event_loop.run();
+        thread::current().id()
+    });
+    let report = handler.emit("frame", SlotArgs::None).unwrap();
+    assert!(report.is_ok());
+    handle.quit();
+    let loop_thread = runner.join().unwrap();
+    assert_eq!(*ran_on.lock().unwrap(), Some(loop_thread));
+    assert!(!handle.is_current());
+}
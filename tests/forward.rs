@@ -0,0 +1,93 @@
+use std::sync::{Arc, Mutex};
+use sigs_slots::*;
+
+fn cycle(from: &str, to: &str) -> Result<ConnectionId, ConnectError> {
+    Err(ConnectError::Cycle { from: Signal::from(from), to: Signal::from(to) })
+}
+
+#[test]
+fn forwarding_to_itself_is_a_cycle() {
+    let handler = SignalAndSlotHandler::new();
+    assert_eq!(handler.connect_forward("a", "a"), cycle("a", "a"));
+    assert_eq!(handler.connection_count(), 0);
+}
+
+#[test]
+fn forwarding_back_is_a_cycle() {
+    let handler = SignalAndSlotHandler::new();
+    handler.connect_forward("a", "b").unwrap();
+    assert_eq!(handler.connect_forward("b", "a"), cycle("b", "a"));
+}
+
+#[test]
+fn forwarding_back_through_other_signals_is_a_cycle() {
+    let handler = SignalAndSlotHandler::new();
+    handler.connect_forward("a", "b").unwrap();
+    handler.connect_forward("b", "c").unwrap();
+    assert_eq!(handler.connect_forward("c", "a"), cycle("c", "a"));
+    //a diamond isn't a cycle
+    handler.connect_forward("a", "c").unwrap();
+}
+
+#[test]
+fn mapped_forwards_count_for_cycles() {
+    let handler = SignalAndSlotHandler::new();
+    handler.connect_forward_map("a", "b", |_| SlotArgs::None).unwrap();
+    assert_eq!(handler.connect_forward("b", "a"), cycle("b", "a"));
+    assert_eq!(handler.connect_forward_map("b", "a", |args| args), cycle("b", "a"));
+}
+
+#[test]
+fn forwarding_between_declared_kinds_must_match() {
+    let handler = SignalAndSlotHandler::new();
+    handler.declare("count", ArgKind::Int).unwrap();
+    handler.declare("text", ArgKind::String).unwrap();
+    assert_eq!(handler.connect_forward("count", "text"), Err(ConnectError::ForwardKind {
+        from: Signal::from("count"),
+        from_kind: ArgKind::Int,
+        to: Signal::from("text"),
+        to_kind: ArgKind::String,
+    }));
+    //a map converts the arguments, so the kinds may differ
+    handler.connect_forward_map("count", "text", |args| match args {
+        SlotArgs::Int(num) => SlotArgs::String(num.to_string()),
+        other => other,
+    }).unwrap();
+}
+
+#[test]
+fn declaring_across_existing_forward_must_match() {
+    let handler = SignalAndSlotHandler::new();
+    handler.declare("count", ArgKind::Int).unwrap();
+    handler.connect_forward("count", "text").unwrap();
+    assert_eq!(handler.declare("text", ArgKind::String), Err(ConnectError::ForwardKind {
+        from: Signal::from("count"),
+        from_kind: ArgKind::Int,
+        to: Signal::from("text"),
+        to_kind: ArgKind::String,
+    }));
+}
+
+#[test]
+fn forwarded_slots_report_under_the_forward() {
+    let handler = SignalAndSlotHandler::new();
+    let seen = Arc::new(Mutex::new(vec![]));
+    let slot_seen = Arc::clone(&seen);
+    let slot = handler.connect("text", create::string_slot(move |text: String| -> Result<(), SlotError> {
+        slot_seen.lock().unwrap().push(text);
+        Ok(())
+    })).unwrap();
+    let forward = handler.connect_forward_map("count", "text", |args| match args {
+        SlotArgs::Int(num) => SlotArgs::String(num.to_string()),
+        other => other,
+    }).unwrap();
+
+    let report = handler.emit("count", SlotArgs::Int(3)).unwrap();
+    assert_eq!(report.len(), 1);
+    match report.outcome(forward) {
+        Some(SlotOutcome::Forwarded(nested)) => assert!(matches!(nested.outcome(slot), Some(SlotOutcome::Success))),
+        other => panic!("expected a forwarded report, got {:?}", other),
+    }
+    assert_eq!(report.invoked(), 1);
+    assert_eq!(*seen.lock().unwrap(), vec![String::from("3")]);
+}
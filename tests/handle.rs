@@ -0,0 +1,61 @@
+use std::sync::mpsc;
+use std::sync::Mutex;
+use std::time::Duration;
+use sigs_slots::*;
+
+//a slot that runs until the returned sender fires or is dropped
+fn gated_slot(handler: &SignalAndSlotHandler, signal: &str) -> (ConnectionId, mpsc::Sender<()>) {
+    let (release, gate) = mpsc::channel::<()>();
+    let gate = Mutex::new(gate);
+    let id = handler.connect(signal, create::none_slot(move || -> Result<(), SlotError> {
+        let _ = gate.lock().unwrap().recv();
+        Ok(())
+    })).unwrap();
+    (id, release)
+}
+
+#[test]
+fn wait_timeout_reports_unfinished_slots() {
+    let handler = SignalAndSlotHandler::new();
+    let (_, release) = gated_slot(&handler, "slow");
+    let mut handle = handler.emit_async("slow", SlotArgs::None).unwrap();
+
+    assert!(!handle.wait_timeout(Duration::from_millis(20)));
+    assert!(!handle.is_finished());
+    assert!(handle.report().is_none());
+
+    release.send(()).unwrap();
+    assert!(handle.wait_timeout(Duration::from_secs(5)));
+    assert!(handle.report().is_some_and(|report| report.is_ok()));
+}
+
+#[test]
+fn into_report_times_out_running_slots() {
+    let handler = SignalAndSlotHandler::new();
+    let fast = handler.connect_with("mixed", create::none_slot(|| -> Result<(), SlotError> { Ok(()) }), ConnectionType::Direct).unwrap();
+    let (slow, release) = gated_slot(&handler, "mixed");
+    let handle = handler.emit_async("mixed", SlotArgs::None).unwrap();
+
+    let report = handle.into_report();
+    assert_eq!(report.len(), 2);
+    assert!(matches!(report.outcome(fast), Some(SlotOutcome::Success)));
+    assert!(matches!(report.outcome(slow), Some(SlotOutcome::Failed(SlotError::Timeout))));
+    assert_eq!(report.failures().count(), 1);
+    drop(release);
+}
+
+#[test]
+fn timed_out_forward_keeps_partial_report() {
+    let handler = SignalAndSlotHandler::new();
+    let (slow, release) = gated_slot(&handler, "target");
+    let forward = handler.connect_forward("source", "target").unwrap();
+    let report = handler.emit_async("source", SlotArgs::None).unwrap().into_report();
+
+    match report.outcome(forward) {
+        Some(SlotOutcome::Forwarded(nested)) => {
+            assert!(matches!(nested.outcome(slow), Some(SlotOutcome::Failed(SlotError::Timeout))));
+        }
+        other => panic!("expected a forwarded report, got {:?}", other),
+    }
+    drop(release);
+}
@@ -0,0 +1,103 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+use sigs_slots::*;
+
+fn single_worker(queue_bound: Option<usize>) -> SignalAndSlotHandler {
+    SignalAndSlotHandler::with_pool(PoolConfig {
+        size: 1,
+        thread_name: String::from("test-worker"),
+        queue_bound,
+        ..PoolConfig::default()
+    })
+}
+
+#[test]
+fn full_queue_blocks_the_emitter() {
+    let handler = Arc::new(single_worker(Some(1)));
+    let (started_sender, started) = mpsc::channel();
+    let (release, gate) = mpsc::channel::<()>();
+    let started_sender = Mutex::new(started_sender);
+    let gate = Mutex::new(gate);
+    handler.connect("work", create::none_slot(move || -> Result<(), SlotError> {
+        started_sender.lock().unwrap().send(()).unwrap();
+        gate.lock().unwrap().recv().unwrap();
+        Ok(())
+    })).unwrap();
+
+    //the first slot occupies the worker, the second fills the queue
+    let first = handler.emit_async("work", SlotArgs::None).unwrap();
+    started.recv().unwrap();
+    let second = handler.emit_async("work", SlotArgs::None).unwrap();
+
+    let (returned_sender, returned) = mpsc::channel();
+    let emitter = {
+        let handler = Arc::clone(&handler);
+        thread::spawn(move || {
+            let third = handler.emit_async("work", SlotArgs::None).unwrap();
+            returned_sender.send(()).unwrap();
+            third.wait()
+        })
+    };
+    assert_eq!(returned.recv_timeout(Duration::from_millis(100)), Err(RecvTimeoutError::Timeout));
+
+    for _ in 0..3 {
+        release.send(()).unwrap();
+    }
+    returned.recv_timeout(Duration::from_secs(5)).unwrap();
+    assert!(first.wait().is_ok());
+    assert!(second.wait().is_ok());
+    assert!(emitter.join().unwrap().is_ok());
+}
+
+#[test]
+fn shutdown_runs_queued_slots_first() {
+    let handler = single_worker(None);
+    let count = Arc::new(AtomicUsize::new(0));
+    let slot_count = Arc::clone(&count);
+    handler.connect("work", create::none_slot(move || -> Result<(), SlotError> {
+        thread::sleep(Duration::from_millis(10));
+        slot_count.fetch_add(1, Ordering::SeqCst);
+        Ok(())
+    })).unwrap();
+
+    let handles = (0..5).map(|_| handler.emit_async("work", SlotArgs::None).unwrap()).collect::<Vec<EmitHandle>>();
+    handler.shutdown();
+    assert_eq!(count.load(Ordering::SeqCst), 5);
+    assert!(handles.into_iter().all(|handle| handle.wait().is_ok()));
+
+    //after shutdown slots still run, on a thread of their own
+    assert_eq!(handler.emit("work", SlotArgs::None).unwrap().invoked(), 1);
+    assert_eq!(count.load(Ordering::SeqCst), 6);
+}
+
+#[test]
+fn nested_emit_from_worker_does_not_starve_the_pool() {
+    let handler = Arc::new(single_worker(None));
+    let inner_thread = Arc::new(Mutex::new(None));
+
+    let slot_thread = Arc::clone(&inner_thread);
+    handler.connect("inner", create::none_slot(move || -> Result<(), SlotError> {
+        *slot_thread.lock().unwrap() = Some(thread::current().name().map(String::from));
+        Ok(())
+    })).unwrap();
+    let weak = Arc::downgrade(&handler);
+    handler.connect("outer", create::none_slot(move || -> Result<(), SlotError> {
+        let handler = weak.upgrade().ok_or("handler gone")?;
+        let report = handler.emit("inner", SlotArgs::None).map_err(SlotError::user)?;
+        if report.invoked() == 1 { Ok(()) } else { Err(SlotError::from("inner slot didn't run")) }
+    })).unwrap();
+
+    let (done_sender, done) = mpsc::channel();
+    let emitter = {
+        let handler = Arc::clone(&handler);
+        thread::spawn(move || done_sender.send(handler.emit("outer", SlotArgs::None).unwrap().is_ok()).unwrap())
+    };
+    assert_eq!(done.recv_timeout(Duration::from_secs(5)), Ok(true));
+    emitter.join().unwrap();
+    //the only worker was busy with the outer slot, so the inner one got its own thread
+    let inner_thread = inner_thread.lock().unwrap().clone().unwrap();
+    assert_ne!(inner_thread.as_deref(), Some("test-worker-0"));
+}
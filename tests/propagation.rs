@@ -0,0 +1,126 @@
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use sigs_slots::*;
+
+mod common;
+use common::counting_slot;
+
+fn sequential() -> SignalAndSlotHandler {
+    let handler = SignalAndSlotHandler::new();
+    handler.set_dispatch_mode(DispatchMode::Sequential);
+    handler
+}
+
+#[test]
+fn stop_propagation_skips_lower_priority_slots() {
+    let handler = sequential();
+    let later = Arc::new(AtomicUsize::new(0));
+    let consumer = handler.connect("key", create::none_slot(|| -> Result<(), SlotError> {
+        assert!(stop_propagation());
+        Ok(())
+    })).unwrap();
+    let skipped = handler.connect("key", counting_slot(&later)).unwrap();
+    handler.set_priority(consumer, 1);
+
+    let report = handler.emit("key", SlotArgs::None).unwrap();
+    assert!(report.is_stopped());
+    assert!(matches!(report.outcome(consumer), Some(SlotOutcome::Success)));
+    assert!(matches!(report.outcome(skipped), Some(SlotOutcome::Stopped)));
+    assert_eq!(later.load(Ordering::SeqCst), 0);
+
+    //the next emit starts with a fresh flag
+    handler.set_priority(consumer, -1);
+    handler.emit("key", SlotArgs::None).unwrap();
+    assert_eq!(later.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn stop_propagation_outside_sequential_emit_does_nothing() {
+    assert!(!stop_propagation());
+
+    let handler = SignalAndSlotHandler::new();
+    let returned = Arc::new(AtomicBool::new(true));
+    let slot_returned = Arc::clone(&returned);
+    handler.connect_with("key", create::none_slot(move || -> Result<(), SlotError> {
+        slot_returned.store(stop_propagation(), Ordering::SeqCst);
+        Ok(())
+    }), ConnectionType::Direct).unwrap();
+    handler.emit("key", SlotArgs::None).unwrap();
+    assert!(!returned.load(Ordering::SeqCst));
+}
+
+#[test]
+fn nested_parallel_emit_cant_stop_the_outer_emit() {
+    let outer = Arc::new(sequential());
+    let inner = Arc::new(SignalAndSlotHandler::new());
+    let returned = Arc::new(AtomicBool::new(true));
+    let after = Arc::new(AtomicUsize::new(0));
+
+    let slot_returned = Arc::clone(&returned);
+    inner.connect_with("inner", create::none_slot(move || -> Result<(), SlotError> {
+        slot_returned.store(stop_propagation(), Ordering::SeqCst);
+        Ok(())
+    }), ConnectionType::Direct).unwrap();
+
+    let nested = Arc::clone(&inner);
+    outer.connect_with("outer", create::none_slot(move || -> Result<(), SlotError> {
+        nested.emit("inner", SlotArgs::None).map(|_| ()).map_err(SlotError::user)
+    }), ConnectionType::Direct).unwrap();
+    outer.connect("outer", counting_slot(&after)).unwrap();
+
+    let report = outer.emit("outer", SlotArgs::None).unwrap();
+    assert!(!returned.load(Ordering::SeqCst));
+    assert!(!report.is_stopped());
+    assert_eq!(after.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn nested_sequential_emit_only_stops_itself() {
+    let outer = Arc::new(sequential());
+    let inner = Arc::new(sequential());
+    let inner_after = Arc::new(AtomicUsize::new(0));
+    let outer_after = Arc::new(AtomicUsize::new(0));
+
+    inner.connect_with("inner", create::none_slot(|| -> Result<(), SlotError> {
+        stop_propagation();
+        Ok(())
+    }), ConnectionType::Direct).unwrap();
+    inner.connect("inner", counting_slot(&inner_after)).unwrap();
+
+    let nested = Arc::clone(&inner);
+    outer.connect_with("outer", create::none_slot(move || -> Result<(), SlotError> {
+        let report = nested.emit("inner", SlotArgs::None).map_err(SlotError::user)?;
+        assert!(report.is_stopped());
+        Ok(())
+    }), ConnectionType::Direct).unwrap();
+    outer.connect("outer", counting_slot(&outer_after)).unwrap();
+
+    let report = outer.emit("outer", SlotArgs::None).unwrap();
+    assert!(report.is_ok());
+    assert!(!report.is_stopped());
+    assert_eq!(inner_after.load(Ordering::SeqCst), 0);
+    assert_eq!(outer_after.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn queued_slot_cant_stop_the_emit_that_queued_it() {
+    let handler = sequential();
+    let event_loop = EventLoop::new();
+    let returned = Arc::new(AtomicBool::new(true));
+    let later = Arc::new(AtomicUsize::new(0));
+
+    let slot_returned = Arc::clone(&returned);
+    let queued = handler.connect_on("key", create::none_slot(move || -> Result<(), SlotError> {
+        slot_returned.store(stop_propagation(), Ordering::SeqCst);
+        Ok(())
+    }), &event_loop.handle(), ConnectionType::Queued).unwrap();
+    handler.set_priority(queued, 1);
+    handler.connect("key", counting_slot(&later)).unwrap();
+
+    let report = handler.emit("key", SlotArgs::None).unwrap();
+    assert!(matches!(report.outcome(queued), Some(SlotOutcome::Queued)));
+    assert_eq!(later.load(Ordering::SeqCst), 1);
+    assert_eq!(event_loop.process_events(), 1);
+    assert!(!returned.load(Ordering::SeqCst));
+    assert!(!report.is_stopped());
+}
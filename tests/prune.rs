@@ -0,0 +1,63 @@
+use std::sync::{Arc, Mutex};
+use sigs_slots::*;
+
+struct Counter {
+    count: i32,
+}
+
+impl Counter {
+    fn add(&mut self, val: i32) -> Result<(), String> {
+        self.count += val;
+        Ok(())
+    }
+}
+
+#[test]
+fn dead_weak_receiver_is_reported_and_pruned_on_emit() {
+    let handler = SignalAndSlotHandler::new();
+    let counter = Arc::new(Mutex::new(Counter { count: 0 }));
+    let id = handler.connect("add", create::weak_int_method_slot(Counter::add, Arc::downgrade(&counter))).unwrap();
+
+    handler.emit("add", SlotArgs::Int(2)).unwrap();
+    assert_eq!(counter.lock().unwrap().count, 2);
+
+    drop(counter);
+    let report = handler.emit("add", SlotArgs::Int(2)).unwrap();
+    assert!(matches!(report.outcome(id), Some(SlotOutcome::ReceiverDropped)));
+    assert_eq!(handler.connection_count(), 0);
+    assert_eq!(handler.pruned_count(), 1);
+}
+
+#[test]
+fn connect_prunes_only_its_own_signal() {
+    let handler = SignalAndSlotHandler::new();
+    let first = Arc::new(Mutex::new(Counter { count: 0 }));
+    let second = Arc::new(Mutex::new(Counter { count: 0 }));
+    handler.connect("a", create::weak_int_method_slot(Counter::add, Arc::downgrade(&first))).unwrap();
+    handler.connect("b", create::weak_int_method_slot(Counter::add, Arc::downgrade(&second))).unwrap();
+    drop(first);
+    drop(second);
+
+    handler.connect("a", create::int_slot(|_| -> Result<(), SlotError> { Ok(()) })).unwrap();
+    assert_eq!(handler.pruned_count(), 1);
+    assert_eq!(handler.receivers("a").len(), 1);
+    assert_eq!(handler.receivers("b").len(), 1);
+
+    assert_eq!(handler.prune(), 1);
+    assert!(!handler.is_connected("b"));
+}
+
+#[test]
+fn strong_receiver_is_kept_alive() {
+    let handler = SignalAndSlotHandler::new();
+    let counter = Arc::new(Mutex::new(Counter { count: 0 }));
+    handler.connect("add", create::int_method_slot(Counter::add, Arc::clone(&counter))).unwrap();
+    let weak = Arc::downgrade(&counter);
+    drop(counter);
+
+    let report = handler.emit("add", SlotArgs::Int(3)).unwrap();
+    assert!(report.is_ok());
+    assert_eq!(report.invoked(), 1);
+    assert_eq!(weak.upgrade().unwrap().lock().unwrap().count, 3);
+    assert_eq!(handler.prune(), 0);
+}
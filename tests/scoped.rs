@@ -0,0 +1,46 @@
+use std::sync::Arc;
+use sigs_slots::*;
+
+fn noop() -> Result<(), SlotError> {
+    Ok(())
+}
+
+struct Widget {
+    _clicked: ScopedConnection<'static>,
+}
+
+#[test]
+fn borrowed_guard_disconnects_on_drop() {
+    let handler = SignalAndSlotHandler::new();
+    {
+        let guard = handler.connect_scoped("clicked", create::none_slot(noop)).unwrap();
+        assert!(handler.receivers("clicked").iter().any(|info| info.id == guard.id()));
+    }
+    assert_eq!(handler.connection_count(), 0);
+}
+
+#[test]
+fn widget_can_store_guard_of_shared_handler() {
+    let handler = Arc::new(SignalAndSlotHandler::new());
+    let widgets = (0..3)
+        .map(|_| Widget { _clicked: handler.connect_scoped_arc("clicked", create::none_slot(noop)).unwrap() })
+        .collect::<Vec<Widget>>();
+    assert_eq!(handler.connection_count(), 3);
+    drop(widgets);
+    assert_eq!(handler.connection_count(), 0);
+}
+
+#[test]
+fn owned_guard_outliving_its_handler_does_nothing() {
+    let handler = Arc::new(SignalAndSlotHandler::new());
+    let guard = handler.connect_scoped_arc("clicked", create::none_slot(noop)).unwrap();
+    drop(handler);
+    drop(guard);
+}
+
+#[test]
+fn released_guard_keeps_connection() {
+    let handler = Arc::new(SignalAndSlotHandler::new());
+    let id = handler.connect_scoped_arc("clicked", create::none_slot(noop)).unwrap().release();
+    assert_eq!(handler.receivers("clicked")[0].id, id);
+}
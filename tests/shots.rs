@@ -0,0 +1,77 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Barrier};
+use std::thread;
+use sigs_slots::*;
+
+mod common;
+use common::counting_slot;
+
+fn emit_from_threads(handler: &Arc<SignalAndSlotHandler>, threads: usize, emits: usize) -> usize {
+    let barrier = Arc::new(Barrier::new(threads));
+    let emitters = (0..threads)
+        .map(|_| {
+            let handler = Arc::clone(handler);
+            let barrier = Arc::clone(&barrier);
+            thread::spawn(move || {
+                barrier.wait();
+                (0..emits).map(|_| handler.emit("ready", SlotArgs::None).unwrap().invoked()).sum::<usize>()
+            })
+        })
+        .collect::<Vec<_>>();
+    emitters.into_iter().map(|emitter| emitter.join().unwrap()).sum()
+}
+
+#[test]
+fn connect_n_runs_exactly_n_times_with_concurrent_emitters() {
+    for connection_type in [ConnectionType::Direct, ConnectionType::Spawned] {
+        let handler = Arc::new(SignalAndSlotHandler::new());
+        let count = Arc::new(AtomicUsize::new(0));
+        handler.connect_n_with("ready", counting_slot(&count), 5, connection_type).unwrap();
+
+        let invoked = emit_from_threads(&handler, 8, 20);
+        assert_eq!(count.load(Ordering::SeqCst), 5);
+        assert_eq!(invoked, 5);
+        assert!(!handler.is_connected("ready"));
+        assert_eq!(handler.connection_count(), 0);
+    }
+}
+
+#[test]
+fn connect_once_leaves_other_slots_connected() {
+    let handler = Arc::new(SignalAndSlotHandler::new());
+    let once = Arc::new(AtomicUsize::new(0));
+    let always = Arc::new(AtomicUsize::new(0));
+    let id = handler.connect_once("ready", counting_slot(&once)).unwrap();
+    handler.connect("ready", counting_slot(&always)).unwrap();
+
+    assert_eq!(handler.receivers("ready")[0].shots_left, Some(1));
+    emit_from_threads(&handler, 4, 10);
+    assert_eq!(once.load(Ordering::SeqCst), 1);
+    assert_eq!(always.load(Ordering::SeqCst), 40);
+    assert!(handler.receivers("ready").iter().all(|info| info.id != id));
+    assert_eq!(handler.connection_count(), 1);
+}
+
+#[test]
+fn mismatched_emits_dont_use_up_shots() {
+    let handler = SignalAndSlotHandler::new();
+    let count = Arc::new(AtomicUsize::new(0));
+    handler.connect_n_with("ready", counting_slot(&count), 1, ConnectionType::Direct).unwrap();
+
+    handler.emit("ready", SlotArgs::Int(1)).unwrap();
+    assert!(handler.is_connected("ready"));
+    handler.emit("ready", SlotArgs::None).unwrap();
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+    assert!(!handler.is_connected("ready"));
+}
+
+#[test]
+fn zero_shots_are_rejected() {
+    let handler = SignalAndSlotHandler::new();
+    let count = Arc::new(AtomicUsize::new(0));
+    assert_eq!(handler.connect_n("ready", counting_slot(&count), 0), Err(ConnectError::ZeroShots));
+    let event_loop = EventLoop::new();
+    let result = handler.connect_n_on("ready", counting_slot(&count), 0, &event_loop.handle(), ConnectionType::Queued);
+    assert_eq!(result, Err(ConnectError::ZeroShots));
+    assert_eq!(handler.connection_count(), 0);
+}